@@ -0,0 +1,501 @@
+//! Benchmarking setup for pallet-poe
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as PoeModule;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, EnsureOrigin, Get, Hooks, ReservableCurrency, UnixTime};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+
+// 给账户足够的余额用于保留押金
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+// 各字段都达到最大长度的元数据
+fn max_metadata<T: Config>() -> ClaimMetadata {
+	ClaimMetadata {
+		description: vec![b'd'; T::MaxDescriptionLength::get() as usize],
+		content_type: vec![b'c'; T::MaxContentTypeLength::get() as usize],
+		uri: Some(vec![b'u'; T::MaxUriLength::get() as usize]),
+	}
+}
+
+// 账户的配额只剩最后一个，检查和记录配额时需要遍历最多的记录；同时清空当前区块已经创建的数量
+fn fill_quota<T: Config>(who: &T::AccountId) {
+	BlockClaims::<T>::kill();
+	let now = frame_system::Pallet::<T>::block_number();
+	let used = vec![now; T::MaxClaimsPerWindow::get().saturating_sub(1) as usize];
+	QuotaUsages::<T>::insert(who, frame_support::BoundedVec::try_from(used).expect("less than MaxClaimsPerWindow"));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+// 为存证添加a个背书，每个背书者不同，背书内容为最大长度
+fn add_attestations<T: Config>(claim: &[u8], a: u32) -> Result<(), &'static str> {
+	for i in 0 .. a {
+		let attester: T::AccountId = account("attester", i, 0);
+		let payload = vec![0u8; T::MaxAttestationLength::get() as usize];
+		PoeModule::<T>::attest(RawOrigin::Signed(attester).into(), ClaimDigest::Raw, claim.to_vec(), payload)?;
+	}
+	Ok(())
+}
+
+// 注册一个管理员已满的命名空间，admin是最后一个管理员，检查权限时需要遍历所有管理员
+fn full_namespace<T: Config>(admin: &T::AccountId) -> Result<NamespaceId, &'static str> {
+	let owner = funded_account::<T>("ns_owner", 0);
+	PoeModule::<T>::create_namespace(RawOrigin::Signed(owner.clone()).into())?;
+	let namespace = NextNamespaceId::<T>::get() - 1;
+	for i in 1 .. T::MaxNamespaceAdmins::get() {
+		let other: T::AccountId = account("ns_admin", i, 0);
+		PoeModule::<T>::add_namespace_admin(RawOrigin::Signed(owner.clone()).into(), namespace, other)?;
+	}
+	PoeModule::<T>::add_namespace_admin(RawOrigin::Signed(owner).into(), namespace, admin.clone())?;
+	Ok(namespace)
+}
+
+// 权重按存证长度l参数化，l的范围为1..=MaxClaimLength
+benchmarks! {
+	// 最坏情况：在注册的命名空间中创建，并且设置了到期区块，需要写入到期索引
+	create_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let namespace = full_namespace::<T>(&caller)?;
+		let claim = vec![0u8; l as usize];
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		fill_quota::<T>(&caller);
+	}: create_claim_in(RawOrigin::Signed(caller.clone()), namespace, ClaimDigest::Raw, claim.clone(), Some(expiry), Some(max_metadata::<T>()))
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, claim.clone()).unwrap();
+		assert!(PoeModule::<T>::proof_of(&bounded_claim).is_some());
+		assert_eq!(ClaimExpiry::<T>::get(&bounded_claim), Some(expiry));
+		assert!(ClaimInfos::<T>::contains_key(&bounded_claim));
+		assert_last_event::<T>(Event::ClaimCreated(caller, bounded_claim, T::Time::now().as_secs()).into());
+	}
+
+	// 撤销时需要清理a个背书，并从版本链中摘除
+	revoke_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let a in 0 .. T::MaxAttestersPerClaim::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let claim = vec![0u8; l as usize];
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), ClaimDigest::Raw, claim.clone(), Some(expiry), None)?;
+		add_attestations::<T>(&claim, a)?;
+		let new_claim = vec![1u8; l as usize];
+		PoeModule::<T>::supersede_claim(RawOrigin::Signed(caller.clone()).into(), ClaimDigest::Raw, claim.clone(), ClaimDigest::Raw, new_claim.clone())?;
+		let note = vec![0u8; T::MaxRevocationNoteLength::get() as usize];
+	}: _(RawOrigin::Signed(caller.clone()), ClaimDigest::Raw, claim.clone(), 1, note)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let new_bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, new_claim).unwrap();
+		assert!(PoeModule::<T>::proof_of(&bounded_claim).is_none());
+		assert!(PoeModule::<T>::versions(&new_bounded_claim).is_none());
+		assert_eq!(AttestationCount::<T>::get(&bounded_claim), 0);
+		assert!(Revoked::<T>::contains_key(&bounded_claim));
+		assert!(HistoryDeposits::<T>::contains_key(&bounded_claim));
+		assert_last_event::<T>(Event::ClaimRevoked(caller, bounded_claim).into());
+	}
+
+	propose_transfer {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let dest: T::AccountId = account("dest", 0, 0);
+		let namespace = full_namespace::<T>(&caller)?;
+		let claim = vec![0u8; l as usize];
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		PoeModule::<T>::create_claim_in(RawOrigin::Signed(caller.clone()).into(), namespace, ClaimDigest::Raw, claim.clone(), None, None)?;
+	}: transfer_claim_in(RawOrigin::Signed(caller), namespace, ClaimDigest::Raw, claim.clone(), dest.clone(), Some(expiry))
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PendingTransfers::<T>::get(&bounded_claim).map(|pending| pending.dest), Some(dest));
+	}
+
+	accept_transfer {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let dest = funded_account::<T>("dest", 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		PoeModule::<T>::propose_transfer(RawOrigin::Signed(caller).into(), ClaimDigest::Raw, claim.clone(), dest.clone(), None)?;
+	}: _(RawOrigin::Signed(dest.clone()), ClaimDigest::Raw, claim.clone())
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(dest.clone()));
+		assert_eq!(T::Currency::reserved_balance(&dest), PoeModule::<T>::claim_deposit(l as usize));
+	}
+
+	cancel_transfer {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		PoeModule::<T>::propose_transfer(RawOrigin::Signed(caller.clone()).into(), ClaimDigest::Raw, claim.clone(), dest, None)?;
+	}: _(RawOrigin::Signed(caller), ClaimDigest::Raw, claim.clone())
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert!(!PendingTransfers::<T>::contains_key(&bounded_claim));
+	}
+
+	create_batch_root {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let root = H256::repeat_byte(1);
+	}: _(RawOrigin::Signed(caller.clone()), root, 1_000)
+	verify {
+		assert_last_event::<T>(Event::BatchRootCreated(caller, root, 1_000).into());
+	}
+
+	revoke_batch_root {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let root = H256::repeat_byte(1);
+		PoeModule::<T>::create_batch_root(RawOrigin::Signed(caller.clone()).into(), root, 1_000)?;
+	}: _(RawOrigin::Signed(caller.clone()), root)
+	verify {
+		assert!(!BatchRoots::<T>::contains_key(&root));
+		assert_eq!(T::Currency::reserved_balance(&caller), 0u32.into());
+	}
+
+	set_claim_metadata {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let namespace = full_namespace::<T>(&caller)?;
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim_in(RawOrigin::Signed(caller.clone()).into(), namespace, ClaimDigest::Raw, claim.clone(), None, None)?;
+	}: set_claim_metadata_in(RawOrigin::Signed(caller.clone()), namespace, ClaimDigest::Raw, claim.clone(), max_metadata::<T>())
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, claim).unwrap();
+		assert_last_event::<T>(Event::ClaimMetadataSet(caller, bounded_claim).into());
+	}
+
+	// c个存证在同一个区块到期，每个存证属于不同的账户，不受MaxClaimsPerAccount限制
+	// 每个到期的存证有a个背书需要清理，同一个区块还要清理m个过期的承诺
+	on_initialize {
+		let c in 0 .. T::MaxExpiriesPerBlock::get();
+		let m in 0 .. T::MaxCommitmentsPerBlock::get();
+		let a in 0 .. T::MaxAttestersPerClaim::get();
+		let expiry = frame_system::Pallet::<T>::block_number() + T::MaxRevealDelay::get() + 1u32.into();
+		for i in 0 .. c {
+			// 准备阶段不受每个区块的存证数量上限限制
+			BlockClaims::<T>::kill();
+			let owner = funded_account::<T>("owner", i);
+			let claim = i.to_le_bytes().to_vec();
+			PoeModule::<T>::create_claim(RawOrigin::Signed(owner).into(), ClaimDigest::Raw, claim.clone(), Some(expiry), None)?;
+			add_attestations::<T>(&claim, a)?;
+		}
+		for i in 0 .. m {
+			let committer = funded_account::<T>("committer", i);
+			PoeModule::<T>::commit_claim(RawOrigin::Signed(committer).into(), H256::from_low_u64_be(i as u64))?;
+		}
+	}: {
+		PoeModule::<T>::on_initialize(expiry);
+	}
+	verify {
+		assert!(!Expiries::<T>::contains_key(expiry));
+		assert!(!CommitmentExpiries::<T>::contains_key(expiry));
+		for i in 0 .. m {
+			assert_eq!(T::Currency::reserved_balance(&account::<T::AccountId>("committer", i, 0)), 0u32.into());
+		}
+		for i in 0 .. c {
+			let owner: T::AccountId = account("owner", i, 0);
+			let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, i.to_le_bytes().to_vec()).unwrap();
+			assert!(PoeModule::<T>::proof_of(&bounded_claim).is_none());
+			assert_eq!(AttestationCount::<T>::get(&bounded_claim), 0);
+			assert_eq!(HistoryDeposits::<T>::get(&bounded_claim).map(|(who, _)| who), Some(owner));
+		}
+	}
+
+	// 操作员代替拥有者创建存证，最坏情况与create_claim相同
+	create_claim_on_behalf {
+		let l in 1 .. T::MaxClaimLength::get();
+		let owner = funded_account::<T>("owner", 0);
+		let operator: T::AccountId = whitelisted_caller();
+		PoeModule::<T>::approve_operator(RawOrigin::Signed(owner.clone()).into(), operator.clone(), None, None)?;
+		let namespace = full_namespace::<T>(&owner)?;
+		let claim = vec![0u8; l as usize];
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		fill_quota::<T>(&owner);
+	}: create_claim_on_behalf_in(RawOrigin::Signed(operator.clone()), owner.clone(), namespace, ClaimDigest::Raw, claim.clone(), Some(expiry), Some(max_metadata::<T>()))
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(owner.clone()));
+		assert_last_event::<T>(Event::OperatorActed(operator, owner, bounded_claim, ClaimAction::Create).into());
+	}
+
+	approve_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone(), None, Some(expiry))
+	verify {
+		assert!(Operators::<T>::contains_key(&caller, &operator));
+	}
+
+	revoke_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		PoeModule::<T>::approve_operator(RawOrigin::Signed(caller.clone()).into(), operator.clone(), None, None)?;
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone())
+	verify {
+		assert!(!Operators::<T>::contains_key(&caller, &operator));
+	}
+
+	// 被取代的存证已经有上一个版本
+	supersede_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let first = vec![2u8; l as usize];
+		let claim = vec![0u8; l as usize];
+		let new_claim = vec![1u8; l as usize];
+		let namespace = full_namespace::<T>(&caller)?;
+		PoeModule::<T>::create_claim_in(RawOrigin::Signed(caller.clone()).into(), namespace, ClaimDigest::Raw, first.clone(), None, None)?;
+		PoeModule::<T>::supersede_claim_in(RawOrigin::Signed(caller.clone()).into(), namespace, ClaimDigest::Raw, first, ClaimDigest::Raw, claim.clone())?;
+		fill_quota::<T>(&caller);
+	}: supersede_claim_in(RawOrigin::Signed(caller.clone()), namespace, ClaimDigest::Raw, claim.clone(), ClaimDigest::Raw, new_claim.clone())
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, claim).unwrap();
+		let new_bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, new_claim).unwrap();
+		assert_eq!(PoeModule::<T>::latest_version(&bounded_claim), new_bounded_claim.clone());
+		assert_last_event::<T>(Event::ClaimSuperseded(caller, bounded_claim, new_bounded_claim).into());
+	}
+
+	create_namespace {
+		let caller = funded_account::<T>("caller", 0);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		let namespace = NextNamespaceId::<T>::get() - 1;
+		assert_eq!(Namespaces::<T>::get(namespace).map(|ns| ns.owner), Some(caller.clone()));
+		assert_last_event::<T>(Event::NamespaceCreated(caller, namespace).into());
+	}
+
+	// 最坏情况：添加最后一个管理员，需要遍历已有的管理员检查是否重复
+	add_namespace_admin {
+		let caller = funded_account::<T>("caller", 0);
+		PoeModule::<T>::create_namespace(RawOrigin::Signed(caller.clone()).into())?;
+		let namespace = NextNamespaceId::<T>::get() - 1;
+		for i in 1 .. T::MaxNamespaceAdmins::get() {
+			let other: T::AccountId = account("ns_admin", i, 0);
+			PoeModule::<T>::add_namespace_admin(RawOrigin::Signed(caller.clone()).into(), namespace, other)?;
+		}
+		let admin: T::AccountId = account("admin", 0, 0);
+	}: _(RawOrigin::Signed(caller), namespace, admin.clone())
+	verify {
+		assert!(PoeModule::<T>::is_namespace_admin(namespace, &admin));
+		assert_last_event::<T>(Event::NamespaceAdminAdded(namespace, admin).into());
+	}
+
+	// 最坏情况：删除最后一个管理员
+	remove_namespace_admin {
+		let admin: T::AccountId = account("admin", 0, 0);
+		let namespace = full_namespace::<T>(&admin)?;
+		let owner: T::AccountId = account("ns_owner", 0, 0);
+	}: _(RawOrigin::Signed(owner), namespace, admin.clone())
+	verify {
+		assert!(!PoeModule::<T>::is_namespace_admin(namespace, &admin));
+		assert_last_event::<T>(Event::NamespaceAdminRemoved(namespace, admin).into());
+	}
+
+	// relayer代替受益人创建存证，包含签名校验
+	create_claim_for {
+		let l in 1 .. T::MaxClaimLength::get();
+		let relayer: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&relayer, BalanceOf::<T>::max_value() / 2u32.into());
+		let beneficiary = T::BenchmarkHelper::account(1);
+		let claim = vec![0u8; l as usize];
+		let payload = PoeModule::<T>::create_claim_payload(&beneficiary, ClaimDigest::Raw, &claim, 0);
+		let signature = T::BenchmarkHelper::sign(1, &payload);
+		fill_quota::<T>(&beneficiary);
+	}: _(RawOrigin::Signed(relayer.clone()), beneficiary.clone(), ClaimDigest::Raw, claim.clone(), 0, signature)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(beneficiary.clone()));
+		assert_eq!(Nonces::<T>::get(&beneficiary), 1);
+		assert_last_event::<T>(Event::ClaimCreated(beneficiary, bounded_claim, T::Time::now().as_secs()).into());
+	}
+
+	// 工作量证明在提交之前计算，这里只包含校验
+	create_claim_unsigned {
+		let l in 1 .. T::MaxClaimLength::get();
+		let claim = vec![0u8; l as usize];
+		let nonce = (0u64..)
+			.find(|nonce| PoeModule::<T>::meets_difficulty(&PoeModule::<T>::unsigned_work(ClaimDigest::Raw, &claim, *nonce)))
+			.unwrap();
+		fill_quota::<T>(&PoeModule::<T>::account_id());
+	}: _(RawOrigin::None, ClaimDigest::Raw, claim.clone(), nonce)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(PoeModule::<T>::account_id()));
+	}
+
+	commit_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let commitment = H256::repeat_byte(1);
+	}: _(RawOrigin::Signed(caller.clone()), commitment)
+	verify {
+		assert_last_event::<T>(Event::ClaimCommitted(caller, commitment).into());
+	}
+
+	reveal_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let claim = vec![0u8; l as usize];
+		let salt = H256::repeat_byte(7);
+		let commitment = PoeModule::<T>::commitment_of(ClaimDigest::Raw, &claim, &salt);
+		PoeModule::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::MinRevealDelay::get(),
+		);
+		fill_quota::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), ClaimDigest::Raw, claim.clone(), salt)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert!(!Commitments::<T>::contains_key(&commitment));
+		assert_last_event::<T>(Event::ClaimRevealed(caller, bounded_claim).into());
+	}
+
+	// 承诺之后别人抢先创建了同一个存证，揭示时把存证和押金转给承诺者
+	reveal_claim_takeover {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let owner = funded_account::<T>("owner", 0);
+		let claim = vec![0u8; l as usize];
+		let salt = H256::repeat_byte(7);
+		let commitment = PoeModule::<T>::commitment_of(ClaimDigest::Raw, &claim, &salt);
+		let committed_at = frame_system::Pallet::<T>::block_number();
+		PoeModule::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+		frame_system::Pallet::<T>::set_block_number(committed_at + 1u32.into());
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		frame_system::Pallet::<T>::set_block_number(
+			committed_at + T::MinRevealDelay::get().max(1u32.into()),
+		);
+	}: reveal_claim(RawOrigin::Signed(caller.clone()), ClaimDigest::Raw, claim.clone(), salt)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(caller.clone()));
+		assert_eq!(T::Currency::reserved_balance(&owner), 0u32.into());
+		assert_last_event::<T>(Event::ClaimRevealed(caller, bounded_claim).into());
+	}
+
+	force_create_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let origin = T::ForceOrigin::successful_origin();
+		let owner = funded_account::<T>("owner", 0);
+		let namespace = full_namespace::<T>(&owner)?;
+		let claim = vec![0u8; l as usize];
+		fill_quota::<T>(&owner);
+	}: force_create_claim_in<T::Origin>(origin, owner.clone(), namespace, ClaimDigest::Raw, claim.clone(), 1)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim_in(namespace, ClaimDigest::Raw, claim).unwrap();
+		assert_last_event::<T>(Event::ClaimForceCreated(owner, bounded_claim, 1).into());
+	}
+
+	force_revoke_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let a in 0 .. T::MaxAttestersPerClaim::get();
+		let origin = T::ForceOrigin::successful_origin();
+		let owner = funded_account::<T>("owner", 0);
+		let claim = vec![0u8; l as usize];
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), ClaimDigest::Raw, claim.clone(), Some(expiry), None)?;
+		add_attestations::<T>(&claim, a)?;
+		let note = vec![0u8; T::MaxRevocationNoteLength::get() as usize];
+	}: _<T::Origin>(origin, ClaimDigest::Raw, claim.clone(), 1, note)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert!(PoeModule::<T>::proof_of(&bounded_claim).is_none());
+		assert_last_event::<T>(Event::ClaimForceRevoked(owner, bounded_claim, 1).into());
+	}
+
+	force_transfer_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let origin = T::ForceOrigin::successful_origin();
+		let owner = funded_account::<T>("owner", 0);
+		let dest = funded_account::<T>("dest", 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		PoeModule::<T>::propose_transfer(RawOrigin::Signed(owner).into(), ClaimDigest::Raw, claim.clone(), dest.clone(), None)?;
+	}: _<T::Origin>(origin, ClaimDigest::Raw, claim.clone(), dest.clone(), 1)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(dest));
+		assert!(!PendingTransfers::<T>::contains_key(&bounded_claim));
+	}
+
+	dispute_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let owner = funded_account::<T>("owner", 0);
+		let challenger: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&challenger, BalanceOf::<T>::max_value() / 2u32.into());
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+	}: _(RawOrigin::Signed(challenger.clone()), ClaimDigest::Raw, claim.clone())
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_last_event::<T>(Event::ClaimDisputed(challenger, owner, bounded_claim).into());
+	}
+
+	// 最坏情况：支持争议，存证转给发起者
+	resolve_dispute {
+		let l in 1 .. T::MaxClaimLength::get();
+		let origin = T::ArbitrationOrigin::successful_origin();
+		let owner = funded_account::<T>("owner", 0);
+		let challenger = funded_account::<T>("challenger", 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		PoeModule::<T>::dispute_claim(RawOrigin::Signed(challenger.clone()).into(), ClaimDigest::Raw, claim.clone())?;
+	}: _<T::Origin>(origin, ClaimDigest::Raw, claim.clone(), DisputeResolution::Upheld)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_eq!(PoeModule::<T>::proof_of(&bounded_claim).map(|record| record.owner), Some(challenger.clone()));
+		assert_last_event::<T>(Event::DisputeResolved(challenger, bounded_claim, DisputeResolution::Upheld).into());
+	}
+
+	// 最坏情况：新的背书者，背书内容为最大长度
+	attest {
+		let l in 1 .. T::MaxClaimLength::get();
+		let owner = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		let payload = vec![0u8; T::MaxAttestationLength::get() as usize];
+	}: _(RawOrigin::Signed(caller.clone()), ClaimDigest::Raw, claim.clone(), payload)
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert_last_event::<T>(Event::ClaimAttested(caller, bounded_claim).into());
+	}
+
+	withdraw_attestation {
+		let l in 1 .. T::MaxClaimLength::get();
+		let owner = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(RawOrigin::Signed(owner).into(), ClaimDigest::Raw, claim.clone(), None, None)?;
+		PoeModule::<T>::attest(RawOrigin::Signed(caller.clone()).into(), ClaimDigest::Raw, claim.clone(), vec![1])?;
+	}: _(RawOrigin::Signed(caller.clone()), ClaimDigest::Raw, claim.clone())
+	verify {
+		let bounded_claim = PoeModule::<T>::bounded_claim(ClaimDigest::Raw, claim).unwrap();
+		assert!(!Attestations::<T>::contains_key(&bounded_claim, &caller));
+	}
+
+	impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,118 @@
+//! Signed extensions for pallet-poe
+
+use crate::{Call, ClaimDigest, Config, Error, NamespaceId, Pallet, DEFAULT_NAMESPACE};
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::{fmt, marker::PhantomData};
+
+// 账户配额已用完时返回的自定义错误码
+pub const QUOTA_EXCEEDED: u8 = 1;
+
+// 在交易进入交易池和打包之前检查存证配额，超过配额的创建存证交易直接被过滤
+// 配额按存证的拥有者计算，与do_create_claim中的检查一致
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckClaimQuota<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckClaimQuota<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> CheckClaimQuota<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	// 会创建存证的调用，返回占用配额的账户，即新存证的拥有者
+	fn quota_owner(who: &T::AccountId, call: &<T as frame_system::Config>::Call) -> Option<T::AccountId> {
+		match call.is_sub_type()? {
+			Call::create_claim { .. } | Call::create_claim_in { .. } => Some(who.clone()),
+			Call::reveal_claim { digest, claim, .. } => match Self::claim_owner(DEFAULT_NAMESPACE, *digest, claim) {
+				// 存证已经存在时揭示只会转移所有权，不创建存证，不占用配额
+				Some(_) => None,
+				None => Some(who.clone()),
+			},
+			Call::create_claim_on_behalf { owner, .. } | Call::create_claim_on_behalf_in { owner, .. } =>
+				Some(owner.clone()),
+			Call::create_claim_for { beneficiary, .. } => Some(beneficiary.clone()),
+			Call::supersede_claim { digest, claim, .. } => Self::claim_owner(DEFAULT_NAMESPACE, *digest, claim),
+			Call::supersede_claim_in { namespace, digest, claim, .. } => Self::claim_owner(*namespace, *digest, claim),
+			_ => None,
+		}
+	}
+
+	// 存证当前的拥有者，存证不存在时返回None
+	// supersede的新版本属于旧版本的拥有者，旧版本不存在时交易在执行时失败，这里不检查
+	fn claim_owner(namespace: NamespaceId, digest: ClaimDigest, claim: &[u8]) -> Option<T::AccountId> {
+		let bounded_claim = Pallet::<T>::bounded_claim_in(namespace, digest, claim.to_vec()).ok()?;
+		Pallet::<T>::proof_of(&bounded_claim).map(|record| record.owner)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckClaimQuota<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckClaimQuota<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckClaimQuota")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckClaimQuota<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckClaimQuota";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if let Some(owner) = Self::quota_owner(who, call) {
+			// 区块的配额用完时稍后还可以打包，账户的配额用完则要等到窗口中最早的存证滑出窗口
+			Pallet::<T>::ensure_quota(&owner).map_err(|e| match e {
+				Error::<T>::BlockQuotaExceeded => InvalidTransaction::ExhaustsResources,
+				_ => InvalidTransaction::Custom(QUOTA_EXCEEDED),
+			})?;
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
@@ -0,0 +1,158 @@
+//! Implementations for `nonfungibles` traits.
+//!
+//! 所有存证属于同一个集合，默认命名空间中的每个存证（摘要算法 + 摘要内容）是集合中的一个物品。
+
+use super::*;
+use codec::Encode;
+use frame_support::{
+	ensure,
+	traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
+};
+use sp_runtime::{DispatchResult, TokenError};
+use sp_std::{boxed::Box, prelude::*};
+
+impl<T: Config> Inspect<<T as frame_system::Config>::AccountId> for Pallet<T> {
+	type ItemId = ClaimBody<T>;
+	type CollectionId = ();
+
+	fn owner(_collection: &Self::CollectionId, item: &Self::ItemId) -> Option<T::AccountId> {
+		Self::proof_of(&(DEFAULT_NAMESPACE, item.clone())).map(|record| record.owner)
+	}
+
+	// 存证的元数据和时间作为物品的属性：
+	// description、content_type、uri为原始字节，created_at、moment、block_number为SCALE编码
+	fn attribute(_collection: &Self::CollectionId, item: &Self::ItemId, key: &[u8]) -> Option<Vec<u8>> {
+		let claim = (DEFAULT_NAMESPACE, item.clone());
+		match key {
+			b"moment" => Self::proof_of(&claim).map(|record| record.moment.encode()),
+			b"block_number" => Self::proof_of(&claim).map(|record| record.block_number.encode()),
+			_ => {
+				let info = ClaimInfos::<T>::get(&claim)?;
+				match key {
+					b"description" => Some(info.description.into_inner()),
+					b"content_type" => Some(info.content_type.into_inner()),
+					b"uri" => info.uri.map(|uri| uri.into_inner()),
+					b"created_at" => Some(info.created_at.encode()),
+					_ => None,
+				}
+			},
+		}
+	}
+
+	// 有争议的存证和无签名提交的公共存证不能转移
+	fn can_transfer(_collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+		let claim = (DEFAULT_NAMESPACE, item.clone());
+		Self::proof_of(&claim).map_or(false, |record| record.owner != Self::account_id()) &&
+			!Disputes::<T>::contains_key(&claim)
+	}
+}
+
+impl<T: Config> InspectEnumerable<<T as frame_system::Config>::AccountId> for Pallet<T> {
+	// 只有一个集合
+	fn collections() -> Box<dyn Iterator<Item = Self::CollectionId>> {
+		Box::new(sp_std::iter::once(()))
+	}
+
+	// 默认命名空间中的所有存证
+	fn items(_collection: &Self::CollectionId) -> Box<dyn Iterator<Item = Self::ItemId>> {
+		Box::new(Proofs::<T>::iter_key_prefix(DEFAULT_NAMESPACE))
+	}
+
+	fn owned(who: &T::AccountId) -> Box<dyn Iterator<Item = (Self::CollectionId, Self::ItemId)>> {
+		Box::new(Self::owned_in_collection(&(), who).map(|item| ((), item)))
+	}
+
+	// 账户在默认命名空间中拥有的存证，不包括无签名提交的公共存证
+	fn owned_in_collection(
+		_collection: &Self::CollectionId,
+		who: &T::AccountId,
+	) -> Box<dyn Iterator<Item = Self::ItemId>> {
+		Box::new(
+			ClaimsByOwner::<T>::iter_key_prefix(who)
+				.filter(|(namespace, _)| *namespace == DEFAULT_NAMESPACE)
+				.map(|(_, item)| item),
+		)
+	}
+}
+
+impl<T: Config> Create<<T as frame_system::Config>::AccountId> for Pallet<T> {
+	// 唯一的集合始终存在，创建集合不做任何修改
+	fn create_collection(
+		_collection: &Self::CollectionId,
+		_who: &T::AccountId,
+		_admin: &T::AccountId,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<T: Config> Mutate<<T as frame_system::Config>::AccountId> for Pallet<T> {
+	// 为who创建存证，押金从who的账户保留
+	fn mint_into(_collection: &Self::CollectionId, item: &Self::ItemId, who: &T::AccountId) -> DispatchResult {
+		let (digest, claim) = item.clone();
+		Self::do_create_claim(who, Some(who), DEFAULT_NAMESPACE, digest, claim.into_inner(), None, None)
+			.map(|_| ())
+	}
+
+	// 撤销存证并留下墓碑，原因代码为0
+	// 不检查拥有者时（由其他模块调用）墓碑记录为拥有者撤销，撤销者为None只表示ForceOrigin强制撤销
+	fn burn(
+		_collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		maybe_check_owner: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let claim = (DEFAULT_NAMESPACE, item.clone());
+		let owner = Self::proof_of(&claim).ok_or(TokenError::UnknownAsset)?.owner;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(check_owner == &owner, Error::<T>::NotClaimOwner);
+		}
+		ensure!(!Disputes::<T>::contains_key(&claim), Error::<T>::ClaimDisputed);
+
+		Self::remove_claim(&claim, &owner);
+		Self::bury_claim(&claim, Some(owner.clone()), 0, Vec::new())?;
+
+		Self::deposit_event(Event::ClaimRevoked(owner, claim));
+		Ok(())
+	}
+
+	// 只能修改description、content_type和uri，创建时间不变
+	fn set_attribute(
+		_collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		let claim = (DEFAULT_NAMESPACE, item.clone());
+		let owner = Self::proof_of(&claim).ok_or(TokenError::UnknownAsset)?.owner;
+
+		ClaimInfos::<T>::try_mutate(&claim, |info| -> DispatchResult {
+			let info = info.as_mut().ok_or(TokenError::UnknownAsset)?;
+			match key {
+				b"description" =>
+					info.description = value.to_vec().try_into().map_err(|_| Error::<T>::DescriptionTooLong)?,
+				b"content_type" =>
+					info.content_type = value.to_vec().try_into().map_err(|_| Error::<T>::ContentTypeTooLong)?,
+				b"uri" => info.uri = Some(value.to_vec().try_into().map_err(|_| Error::<T>::UriTooLong)?),
+				_ => return Err(TokenError::Unsupported.into()),
+			}
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::ClaimMetadataSet(owner, claim));
+		Ok(())
+	}
+}
+
+impl<T: Config> Transfer<<T as frame_system::Config>::AccountId> for Pallet<T> {
+	// 直接转移存证，不需要接收方确认，押金转移到接收方
+	fn transfer(_collection: &Self::CollectionId, item: &Self::ItemId, destination: &T::AccountId) -> DispatchResult {
+		let claim = (DEFAULT_NAMESPACE, item.clone());
+		let owner = Self::proof_of(&claim).ok_or(TokenError::UnknownAsset)?.owner;
+		ensure!(!Disputes::<T>::contains_key(&claim), Error::<T>::ClaimDisputed);
+
+		Self::do_transfer(&claim, &owner, destination)?;
+
+		Self::deposit_event(Event::ClaimTransfered(owner, claim, destination.clone()));
+		Ok(())
+	}
+}
@@ -6,6 +6,18 @@ mod tests;
 #[cfg(test)]
 mod mock;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod extensions;
+mod impl_nonfungibles;
+pub mod merkle;
+pub mod migrations;
+pub mod runtime_api;
+pub mod weights;
+pub use weights::WeightInfo;
+pub use extensions::CheckClaimQuota;
+
 /// A module for proof of the existence
 // pub use frame_system::pallet::*;
 pub use pallet::*;
@@ -15,9 +27,271 @@ pub mod pallet {
     // use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
+    use frame_support::traits::{Currency, OnUnbalanced, ReservableCurrency, UnixTime};
+    use frame_support::storage::StoragePrefixedMap;
+    use frame_support::PalletId;
+    use sp_core::H256;
+    use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Saturating, Verify, Zero};
+    use sp_runtime::ArithmeticError;
 
     use sp_std::prelude::*;
 
+    use crate::{merkle, weights::WeightInfo};
+
+    // 存证押金的余额类型
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    // 被罚没的争议保证金
+    pub type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+    // 存证内容所用的摘要算法，决定了存证内容的合法长度
+    // 同一份文档用不同算法得到的摘要是不同的存证
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ClaimDigest {
+        Blake2_256,
+        Sha2_256,
+        Keccak256,
+        Sha3_512,
+        // 不做长度校验的原始内容，长度不超过MaxClaimLength
+        Raw,
+    }
+
+    impl ClaimDigest {
+        // 摘要的字节长度，Raw没有固定长度
+        pub fn digest_len(&self) -> Option<usize> {
+            match self {
+                ClaimDigest::Blake2_256 | ClaimDigest::Sha2_256 | ClaimDigest::Keccak256 => Some(32),
+                ClaimDigest::Sha3_512 => Some(64),
+                ClaimDigest::Raw => None,
+            }
+        }
+    }
+
+    // 命名空间ID，不同租户的存证互不冲突
+    pub type NamespaceId = u32;
+
+    // 默认命名空间，不需要注册，所有账户都可以在其中创建存证
+    pub const DEFAULT_NAMESPACE: NamespaceId = 0;
+
+    // 命名空间内的存证：摘要算法 + 摘要内容
+    pub type ClaimBody<T> = (ClaimDigest, BoundedVec<u8, <T as Config>::MaxClaimLength>);
+
+    // 存证的存储Key：命名空间 + 命名空间内的存证
+    pub type ClaimOf<T> = (NamespaceId, ClaimBody<T>);
+
+    // 特权操作的原因代码，具体含义由runtime治理约定（如欺诈、法院判决）
+    pub type ReasonCode = u32;
+
+    // 等待接收方确认的存证转移
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct PendingTransfer<AccountId, BlockNumber> {
+        // 接收方
+        pub dest: AccountId,
+        // 转移请求的到期区块，到期后接收方不能再确认
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    // 存证拥有者变更的方式
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum TransferKind {
+        // 创建存证
+        Created,
+        // 接收方确认转移
+        Transferred,
+    }
+
+    // 存证的一条所有权记录
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ProvenanceRecord<AccountId, BlockNumber> {
+        pub owner: AccountId,
+        // 获得存证的区块
+        pub acquired_at: BlockNumber,
+        pub kind: TransferKind,
+    }
+
+    // 所有权记录达到MaxProvenanceLength之后的处理方式
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ProvenancePruning {
+        // 拒绝新的转移和重新创建，保证记录完整
+        RejectWhenFull,
+        // 删除最早的记录，始终保留当前存证的创建记录
+        DropOldest,
+    }
+
+    // 批量存证的Merkle root
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct BatchRoot<AccountId, BlockNumber, Balance> {
+        pub owner: AccountId,
+        // 批量存证中的文档都以这个区块作为存证时间
+        pub created_at: BlockNumber,
+        // 叶子节点（文档摘要）的数量
+        pub leaf_count: u32,
+        pub deposit: Balance,
+    }
+
+    // 创建存证或修改元数据时用户提交的元数据，长度在链上校验
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    pub struct ClaimMetadata {
+        // 存证内容的描述
+        pub description: Vec<u8>,
+        // 存证内容的MIME类型，如 application/pdf
+        pub content_type: Vec<u8>,
+        // 存证内容在链下的地址
+        pub uri: Option<Vec<u8>>,
+    }
+
+    // 链上保存的存证信息
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct ClaimInfo<T: Config> {
+        pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+        pub content_type: BoundedVec<u8, T::MaxContentTypeLength>,
+        pub uri: Option<BoundedVec<u8, T::MaxUriLength>>,
+        // 创建存证时的Unix时间戳（秒）
+        pub created_at: u64,
+    }
+
+    // 存证记录：拥有者，以及拥有者取得存证的区块和Unix时间
+    // v0版本以存证内容为Key直接保存(拥有者, 区块)元组，v1版本改为按命名空间和摘要算法分开保存的结构体，v2版本增加了时间
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ClaimRecord<AccountId, BlockNumber> {
+        pub owner: AccountId,
+        pub block_number: BlockNumber,
+        // 取得存证时的Unix时间戳（秒），从v1迁移过来的存证为0
+        pub moment: u64,
+    }
+
+    // 存证承诺，揭示之前链上只有存证内容和盐的Hash
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Commitment<AccountId, BlockNumber, Balance> {
+        pub who: AccountId,
+        pub committed_at: BlockNumber,
+        // 提交承诺时保留的押金，揭示时退还，过期被清理时罚没
+        pub deposit: Balance,
+    }
+
+    // 对存证所有权的争议
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Dispute<AccountId, Balance, BlockNumber> {
+        // 发起争议的账户
+        pub challenger: AccountId,
+        // 发起争议时保留的保证金
+        pub bond: Balance,
+        pub opened_at: BlockNumber,
+    }
+
+    // 仲裁结果
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum DisputeResolution {
+        // 驳回争议，罚没发起者的保证金
+        Rejected,
+        // 支持争议，存证转给发起者，退还保证金
+        Upheld,
+    }
+
+    // 命名空间的拥有者和管理员
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Namespace<T: Config> {
+        pub owner: T::AccountId,
+        // 管理员可以在命名空间中创建存证，以及撤销和转移命名空间中的存证
+        pub admins: BoundedVec<T::AccountId, T::MaxNamespaceAdmins>,
+        // 注册命名空间时保留的押金
+        pub deposit: BalanceOf<T>,
+    }
+
+    // 被撤销存证的墓碑，记录撤销的原因
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Tombstone<T: Config> {
+        // 撤销存证的账户，None表示由ForceOrigin强制撤销
+        pub revoker: Option<T::AccountId>,
+        pub revoked_at: T::BlockNumber,
+        pub reason: ReasonCode,
+        // 撤销说明
+        pub note: BoundedVec<u8, T::MaxRevocationNoteLength>,
+    }
+
+    // 存证的版本链接：previous为被当前存证取代的旧版本，next为取代当前存证的新版本
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct ClaimVersion<T: Config> {
+        pub previous: Option<ClaimOf<T>>,
+        pub next: Option<ClaimOf<T>>,
+    }
+
+    // 存证的状态，区分从未存在和已被撤销
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub enum ClaimStatus<T: Config> {
+        NeverExisted,
+        // 存证存在（拥有者，区块）
+        Active(T::AccountId, T::BlockNumber),
+        // 存证已被撤销，没有重新创建
+        Revoked(Tombstone<T>),
+    }
+
+    // 第三方对存证的背书
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Attestation<T: Config> {
+        // 背书内容，如审计意见的摘要
+        pub payload: BoundedVec<u8, T::MaxAttestationLength>,
+        pub attested_at: T::BlockNumber,
+    }
+
+    // 操作员可以代替拥有者执行的操作
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ClaimAction {
+        Create,
+        Revoke,
+        // 发起、确认和取消转移
+        Transfer,
+        SetMetadata,
+    }
+
+    // 操作员的权限范围，每个字段对应一种操作
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct OperatorScope {
+        pub create: bool,
+        pub revoke: bool,
+        pub transfer: bool,
+        pub set_metadata: bool,
+    }
+
+    impl OperatorScope {
+        // 允许所有操作
+        pub fn all() -> Self {
+            Self { create: true, revoke: true, transfer: true, set_metadata: true }
+        }
+
+        pub fn allows(&self, action: ClaimAction) -> bool {
+            match action {
+                ClaimAction::Create => self.create,
+                ClaimAction::Revoke => self.revoke,
+                ClaimAction::Transfer => self.transfer,
+                ClaimAction::SetMetadata => self.set_metadata,
+            }
+        }
+    }
+
+    // 拥有者对操作员的授权
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct OperatorApproval<BlockNumber> {
+        pub scope: OperatorScope,
+        // 授权的到期区块，到期后操作员不能再代替拥有者操作
+        pub expires_at: Option<BlockNumber>,
+    }
+
     // 1. 配置(定义)接口
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -27,32 +301,464 @@ pub mod pallet {
          // 链上存储Hash值，长度不变；同时因为是常量，所以用#[pallet::constant]宏声明
         #[pallet::constant]
         type MaxClaimLength: Get<u32>;
+
+        // Currency 类型，用于存证押金的保留和退还
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        // 创建存证需要保留的基础押金
+        #[pallet::constant]
+        type ClaimDepositBase: Get<BalanceOf<Self>>;
+
+        // 存证每个字节需要额外保留的押金，押金随存证长度线性增长
+        #[pallet::constant]
+        type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+
+        // 同一个区块最多可以有多少个存证到期，限制了on_initialize清理到期存证的权重
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+
+        // 存证描述的最大长度
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+
+        // 存证内容类型的最大长度
+        #[pallet::constant]
+        type MaxContentTypeLength: Get<u32>;
+
+        // 存证链下地址的最大长度
+        #[pallet::constant]
+        type MaxUriLength: Get<u32>;
+
+        // 当前的Unix时间，用于记录存证的创建时间
+        type Time: UnixTime;
+
+        // 每个账户最多可以拥有的存证数量
+        #[pallet::constant]
+        type MaxClaimsPerAccount: Get<u32>;
+
+        // 每个存证最多保留的所有权记录数量
+        #[pallet::constant]
+        type MaxProvenanceLength: Get<u32>;
+
+        // 所有权记录满了之后的处理方式
+        #[pallet::constant]
+        type ProvenancePruning: Get<ProvenancePruning>;
+
+        // 链下签名的类型，create_claim_for用它校验受益人对存证请求的签名
+        type Signature: Verify<Signer = Self::Signer> + Parameter;
+
+        // 签名的公钥，可以转换成账户
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+
+        // 模块账户的ID，无签名提交的存证属于这个账户
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        // 无签名提交存证时工作量证明的难度：工作量Hash至少要有多少个前导0比特
+        #[pallet::constant]
+        type UnsignedDifficulty: Get<u32>;
+
+        // 无签名提交存证的交易在交易池中的优先级
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        // 无签名提交存证的交易在交易池中的有效区块数
+        #[pallet::constant]
+        type UnsignedLongevity: Get<TransactionLongevity>;
+
+        // 提交承诺之后至少要等多少个区块才能揭示
+        #[pallet::constant]
+        type MinRevealDelay: Get<Self::BlockNumber>;
+
+        // 提交承诺之后最多在多少个区块内揭示，超过之后承诺被清理
+        #[pallet::constant]
+        type MaxRevealDelay: Get<Self::BlockNumber>;
+
+        // 同一个区块最多可以提交多少个承诺，限制了on_initialize清理过期承诺的权重
+        #[pallet::constant]
+        type MaxCommitmentsPerBlock: Get<u32>;
+
+        // 提交承诺需要保留的押金，防止有人白白占满每个区块的承诺数量
+        #[pallet::constant]
+        type CommitmentDeposit: Get<BalanceOf<Self>>;
+
+        // 可以强制创建、撤销和转移存证的Origin，如Root或议会
+        type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+        // 可以裁决存证争议的Origin
+        type ArbitrationOrigin: EnsureOrigin<Self::Origin>;
+
+        // 发起争议需要保留的保证金
+        #[pallet::constant]
+        type DisputeBond: Get<BalanceOf<Self>>;
+
+        // 争议被驳回时罚没的保证金，以及过期承诺被罚没的押金的去处
+        type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        // 背书内容的最大长度
+        #[pallet::constant]
+        type MaxAttestationLength: Get<u32>;
+
+        // 每个存证最多可以有多少个背书者，限制了撤销存证时清理背书的权重
+        #[pallet::constant]
+        type MaxAttestersPerClaim: Get<u32>;
+
+        // 撤销说明的最大长度
+        #[pallet::constant]
+        type MaxRevocationNoteLength: Get<u32>;
+
+        // 存证被撤销或到期后，押金中继续保留的部分，用于支付保留下来的墓碑和所有权记录的存储
+        // 应该按撤销说明的最大长度和所有权记录的最大数量计算
+        #[pallet::constant]
+        type HistoryDeposit: Get<BalanceOf<Self>>;
+
+        // 是否禁止重新创建已被撤销的存证
+        #[pallet::constant]
+        type ForbidReregistration: Get<bool>;
+
+        // 注册命名空间需要保留的押金
+        #[pallet::constant]
+        type NamespaceDeposit: Get<BalanceOf<Self>>;
+
+        // 每个命名空间最多可以有多少个管理员
+        #[pallet::constant]
+        type MaxNamespaceAdmins: Get<u32>;
+
+        // 每个账户在任意连续QuotaWindow个区块内最多可以创建多少个存证（按存证的拥有者计算）
+        #[pallet::constant]
+        type MaxClaimsPerWindow: Get<u32>;
+
+        // 账户配额滑动窗口的区块数
+        #[pallet::constant]
+        type QuotaWindow: Get<Self::BlockNumber>;
+
+        // 每个区块最多可以创建多少个存证
+        #[pallet::constant]
+        type MaxClaimsPerBlock: Get<u32>;
+
+        // benchmarking中生成受益人的链下签名
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::Signature>;
+
+        // 可调用函数的权重，由benchmarking生成，在runtime中指定具体实现
+        type WeightInfo: WeightInfo;
+    }
+
+    // benchmarking无法通用地构造签名，由runtime提供
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<AccountId, Signature> {
+        // 第seed个测试密钥对应的账户
+        fn account(seed: u32) -> AccountId;
+
+        // 用第seed个测试密钥对message签名
+        fn sign(seed: u32, message: &[u8]) -> Signature;
     }
 
     // 2. 定义模块需要的结构体
+    // 当前的存储版本，存储结构变化时递增，并在migrations模块中添加迁移
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]       // 这个宏生成包含所有存储项的trait
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // 3. 定义要使用的存储项
     // 存储单元
+    // 命名空间 => 存证 => 存证记录
     #[pallet::storage]
     #[pallet::getter(fn proofs)]   
-    pub type Proofs<T: Config> = StorageMap<
+    pub type Proofs<T: Config> = StorageDoubleMap<
         _,
+        Twox64Concat,
+        NamespaceId,
         Blake2_128Concat,                      
-        BoundedVec<u8, T::MaxClaimLength>,      // 不能再用Vec<u8>,
-        (T::AccountId, T::BlockNumber)        
+        ClaimBody<T>,      // 不能再用Vec<u8>,
+        ClaimRecord<T::AccountId, T::BlockNumber>
+    >;
+
+    // 注册的命名空间，默认命名空间不在其中
+    #[pallet::storage]
+    #[pallet::getter(fn namespaces)]
+    pub type Namespaces<T: Config> = StorageMap<_, Twox64Concat, NamespaceId, Namespace<T>>;
+
+    // 下一个注册的命名空间ID，从1开始
+    #[pallet::storage]
+    #[pallet::getter(fn next_namespace_id)]
+    pub type NextNamespaceId<T: Config> = StorageValue<_, NamespaceId, ValueQuery>;
+
+    // 每个存证当前保留押金的账户和金额，退还或转移时以此为准，不受押金参数调整的影响
+    // 通过create_claim_for创建的存证由代付的账户保留押金，转移后由新拥有者保留
+    #[pallet::storage]
+    #[pallet::getter(fn claim_deposits)]
+    pub type ClaimDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        (T::AccountId, BalanceOf<T>),
+    >;
+
+    // 存证的元数据
+    #[pallet::storage]
+    #[pallet::getter(fn claim_info)]
+    pub type ClaimInfos<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        ClaimInfo<T>,
+    >;
+
+    // 按创建时间排列的存证索引：(命名空间, 创建时间的大端字节) => 存证 => ()
+    // 第一个Key不做Hash，同一个命名空间中的Key按创建时间排序，按时间查询时只需要遍历查询的时间范围
+    #[pallet::storage]
+    pub type ClaimsByMoment<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (NamespaceId, [u8; 8]),
+        Blake2_128Concat,
+        ClaimBody<T>,
+        (),
+    >;
+
+    // 等待接收方确认的存证转移
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfers)]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        PendingTransfer<T::AccountId, T::BlockNumber>,
+    >;
+
+    // 存证的所有权记录，按时间顺序追加，存证被撤销或到期后仍然保留，供审计追溯
+    #[pallet::storage]
+    #[pallet::getter(fn provenance)]
+    pub type Provenance<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        BoundedVec<ProvenanceRecord<T::AccountId, T::BlockNumber>, T::MaxProvenanceLength>,
+        ValueQuery,
+    >;
+
+    // 批量存证：Merkle root => 创建者、区块等信息
+    #[pallet::storage]
+    #[pallet::getter(fn batch_roots)]
+    pub type BatchRoots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        BatchRoot<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+    >;
+
+    // 账户拥有的存证索引：拥有者 => 存证，用于分页查询
+    #[pallet::storage]
+    pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        (),
+    >;
+
+    // 账户拥有的存证数量，不超过MaxClaimsPerAccount
+    #[pallet::storage]
+    #[pallet::getter(fn claim_count)]
+    pub type ClaimCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    // 存证的到期区块，撤销存证时用于找到到期索引中的记录
+    #[pallet::storage]
+    #[pallet::getter(fn claim_expiry)]
+    pub type ClaimExpiry<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        T::BlockNumber,
+    >;
+
+    // 到期索引：区块号 => 在该区块到期的存证
+    #[pallet::storage]
+    #[pallet::getter(fn expiries)]
+    pub type Expiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        BoundedVec<ClaimOf<T>, T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    // 操作员授权：拥有者 => 操作员 => 授权范围和到期区块
+    #[pallet::storage]
+    #[pallet::getter(fn operators)]
+    pub type Operators<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        OperatorApproval<T::BlockNumber>,
+    >;
+
+    // 账户下一次create_claim_for要使用的nonce，防止签名被重放
+    #[pallet::storage]
+    #[pallet::getter(fn nonces)]
+    pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    // 存证承诺：blake2_256(摘要算法 ++ 存证内容 ++ 盐) => 提交者和区块
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub type Commitments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        Commitment<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+    >;
+
+    // 过期承诺的索引：区块号 => 在该区块开始时被清理的承诺
+    #[pallet::storage]
+    pub type CommitmentExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        BoundedVec<H256, T::MaxCommitmentsPerBlock>,
+        ValueQuery,
+    >;
+
+    // 通过揭示创建的存证所对应的承诺区块，更早的承诺者揭示时可以取得所有权
+    #[pallet::storage]
+    #[pallet::getter(fn claim_committed_at)]
+    pub type ClaimCommittedAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        T::BlockNumber,
+    >;
+
+    // 拥有者由特权操作确定的存证：ForceOrigin强制创建或转移、仲裁支持争议后转移，以及无签名提交的公共存证
+    // 更早的承诺者揭示时不能取得这些存证，存证被删除或者再次转移时清除
+    #[pallet::storage]
+    pub type PrivilegedClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        (),
+    >;
+
+    // 正在进行中的存证争议，争议期间存证不能撤销和转移
+    #[pallet::storage]
+    #[pallet::getter(fn disputes)]
+    pub type Disputes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        Dispute<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    >;
+
+    // 存证的背书：存证 => 背书者 => 背书内容
+    #[pallet::storage]
+    #[pallet::getter(fn attestations)]
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        Attestation<T>,
+    >;
+
+    // 存证的背书者数量，不超过MaxAttestersPerClaim
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_count)]
+    pub type AttestationCount<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, u32, ValueQuery>;
+
+    // 被撤销存证的墓碑，重新创建存证之后仍然保留，再次撤销时覆盖
+    #[pallet::storage]
+    #[pallet::getter(fn revoked)]
+    pub type Revoked<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, Tombstone<T>>;
+
+    // 存证被删除后为墓碑和所有权记录继续保留押金的账户和金额，同一存证再次被删除时退还
+    #[pallet::storage]
+    #[pallet::getter(fn history_deposits)]
+    pub type HistoryDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ClaimOf<T>,
+        (T::AccountId, BalanceOf<T>),
     >;
 
+    // 存证的版本链接，被取代的旧版本仍然保留存证，只记录指向新版本的链接
+    #[pallet::storage]
+    #[pallet::getter(fn versions)]
+    pub type Versions<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, ClaimVersion<T>>;
+
+    // 账户最近创建存证的区块，按时间顺序排列，只保留滑动窗口内的记录
+    #[pallet::storage]
+    #[pallet::getter(fn quota_usage)]
+    pub type QuotaUsages<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<T::BlockNumber, T::MaxClaimsPerWindow>, ValueQuery>;
+
+    // (区块, 该区块中已经创建的存证数量)，进入新的区块后在下一次创建时重置
+    #[pallet::storage]
+    #[pallet::getter(fn block_claims)]
+    pub type BlockClaims<T: Config> = StorageValue<_, (T::BlockNumber, u32), ValueQuery>;
 
     // 4. 定义事件，可以在交易执行过程中触发
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
-        ClaimCreated(T::AccountId, Vec<u8>),
-        ClaimRevoked(T::AccountId, Vec<u8>),
-        ClaimTransfered(T::AccountId, Vec<u8>, T::AccountId),
+        // 创建了存证（拥有者，存证，Unix时间戳）
+        ClaimCreated(T::AccountId, ClaimOf<T>, u64),
+        ClaimRevoked(T::AccountId, ClaimOf<T>),
+        ClaimTransfered(T::AccountId, ClaimOf<T>, T::AccountId),
+        // 拥有者发起转移（拥有者，存证，接收方，到期区块）
+        TransferProposed(T::AccountId, ClaimOf<T>, T::AccountId, Option<T::BlockNumber>),
+        // 转移请求被取消（拥有者，存证，接收方）
+        TransferCancelled(T::AccountId, ClaimOf<T>, T::AccountId),
+        // 创建了批量存证（创建者，Merkle root，叶子节点数量）
+        BatchRootCreated(T::AccountId, H256, u32),
+        // 撤销了批量存证（创建者，Merkle root）
+        BatchRootRevoked(T::AccountId, H256),
+        // 存证到期被清理（原拥有者，存证）
+        ClaimExpired(T::AccountId, ClaimOf<T>),
+        // 存证拥有者修改了元数据
+        ClaimMetadataSet(T::AccountId, ClaimOf<T>),
+        // 拥有者授权了操作员（拥有者，操作员，权限范围，到期区块）
+        OperatorApproved(T::AccountId, T::AccountId, OperatorScope, Option<T::BlockNumber>),
+        // 拥有者取消了操作员的授权（拥有者，操作员）
+        OperatorRevoked(T::AccountId, T::AccountId),
+        // 操作员代替拥有者操作了存证（操作员，拥有者，存证，操作）
+        OperatorActed(T::AccountId, T::AccountId, ClaimOf<T>, ClaimAction),
+        // 提交了存证承诺（提交者，承诺）
+        ClaimCommitted(T::AccountId, H256),
+        // 揭示了存证承诺，提交者成为存证的拥有者（提交者，存证）
+        ClaimRevealed(T::AccountId, ClaimOf<T>),
+        // 存证的拥有者由特权操作确定，揭示没有取得所有权，只退还了承诺押金（承诺者，存证）
+        RevealSkipped(T::AccountId, ClaimOf<T>),
+        // 承诺超过MaxRevealDelay没有揭示，被清理（提交者，承诺）
+        CommitmentExpired(T::AccountId, H256),
+        // 特权强制创建了存证（拥有者，存证，原因代码）
+        ClaimForceCreated(T::AccountId, ClaimOf<T>, ReasonCode),
+        // 特权强制撤销了存证（原拥有者，存证，原因代码）
+        ClaimForceRevoked(T::AccountId, ClaimOf<T>, ReasonCode),
+        // 特权强制转移了存证（原拥有者，存证，新拥有者，原因代码）
+        ClaimForceTransferred(T::AccountId, ClaimOf<T>, T::AccountId, ReasonCode),
+        // 对存证发起了争议（发起者，拥有者，存证）
+        ClaimDisputed(T::AccountId, T::AccountId, ClaimOf<T>),
+        // 争议被裁决（发起者，存证，裁决结果）
+        DisputeResolved(T::AccountId, ClaimOf<T>, DisputeResolution),
+        // 为存证背书或者修改了背书内容（背书者，存证）
+        ClaimAttested(T::AccountId, ClaimOf<T>),
+        // 撤回了背书（背书者，存证）
+        AttestationWithdrawn(T::AccountId, ClaimOf<T>),
+        // 注册了命名空间（拥有者，命名空间）
+        NamespaceCreated(T::AccountId, NamespaceId),
+        // 添加了命名空间管理员（命名空间，管理员）
+        NamespaceAdminAdded(NamespaceId, T::AccountId),
+        // 删除了命名空间管理员（命名空间，管理员）
+        NamespaceAdminRemoved(NamespaceId, T::AccountId),
+        // 存证被新版本取代（拥有者，旧存证，新存证）
+        ClaimSuperseded(T::AccountId, ClaimOf<T>, ClaimOf<T>),
     }
 
     #[pallet::error]
@@ -65,84 +771,1647 @@ pub mod pallet {
         NotClaimOwner,
 
         // Key过长
-        ClaimTooLong
+        ClaimTooLong,
+        /// 存证内容的长度与摘要算法不符
+        InvalidDigestLength,
+        /// 余额不足，无法保留存证押金
+        InsufficientDeposit,
+        /// 到期区块必须晚于当前区块
+        InvalidExpiry,
+        /// 该区块到期的存证数量已达上限
+        TooManyExpiries,
+        /// 存证没有等待确认的转移
+        NoPendingTransfer,
+        /// 当前账户不是转移的接收方
+        NotTransferRecipient,
+        /// 转移请求已经到期
+        TransferExpired,
+        /// 账户拥有的存证数量已达上限
+        TooManyClaims,
+        /// 存证的所有权记录已满，不能再转移
+        ProvenanceFull,
+        /// 批量存证已经存在
+        BatchRootAlreadyExist,
+        /// 批量存证不存在
+        BatchRootNotExist,
+        /// 批量存证至少需要一个文档
+        EmptyBatch,
+        /// 存证描述过长
+        DescriptionTooLong,
+        /// 存证内容类型过长
+        ContentTypeTooLong,
+        /// 存证链下地址过长
+        UriTooLong,
+        /// 不能把自己设为操作员
+        InvalidOperator,
+        /// 操作员的授权不存在
+        OperatorNotExist,
+        /// 操作员的授权已经到期
+        OperatorExpired,
+        /// 操作员没有执行该操作的权限
+        OperatorNotPermitted,
+        /// nonce与受益人当前的nonce不一致
+        InvalidNonce,
+        /// 签名校验失败
+        InvalidSignature,
+        /// 工作量证明没有达到难度要求
+        InsufficientWork,
+        /// 承诺已经存在
+        CommitmentAlreadyExist,
+        /// 承诺不存在，或者已经过期被清理
+        CommitmentNotExist,
+        /// 当前账户不是承诺的提交者
+        NotCommitter,
+        /// 还没到最早的揭示区块
+        RevealTooEarly,
+        /// 已经超过最晚的揭示区块
+        RevealTooLate,
+        /// 该区块提交的承诺数量已达上限
+        TooManyCommitments,
+        /// 存证正在争议中，不能撤销或转移
+        ClaimDisputed,
+        /// 存证没有正在进行的争议
+        NoDispute,
+        /// 不能对自己的存证发起争议
+        CannotDisputeOwnClaim,
+        /// 背书内容过长
+        AttestationTooLong,
+        /// 存证的背书者数量已达上限
+        TooManyAttestations,
+        /// 背书不存在
+        AttestationNotExist,
+        /// 撤销说明过长
+        NoteTooLong,
+        /// 存证已被撤销，不能重新创建
+        ClaimTombstoned,
+        /// 命名空间不存在
+        NamespaceNotExist,
+        /// 当前账户不是命名空间的拥有者
+        NotNamespaceOwner,
+        /// 当前账户不是命名空间的拥有者或管理员
+        NoNamespacePermission,
+        /// 命名空间的管理员数量已达上限
+        TooManyNamespaceAdmins,
+        /// 该账户已经是命名空间的管理员
+        AlreadyNamespaceAdmin,
+        /// 该账户不是命名空间的管理员
+        NamespaceAdminNotExist,
+        /// 存证已经被新版本取代
+        ClaimAlreadySuperseded,
+        /// 账户在当前配额窗口中创建的存证数量已达上限
+        QuotaExceeded,
+        /// 当前区块创建的存证数量已达上限
+        BlockQuotaExceeded,
+        /// 无签名提交的公共存证属于模块账户，不能转移或发起争议
+        PublicClaim,
+    }
+
+    // 创世存证：(拥有者, 存证内容)，摘要算法为Raw，不保留押金
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub claims: Vec<(T::AccountId, Vec<u8>)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { claims: Default::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for (owner, claim) in &self.claims {
+                assert!(
+                    claim.len() <= T::MaxClaimLength::get() as usize,
+                    "genesis claim exceeds MaxClaimLength"
+                );
+                assert!(
+                    !Pallet::<T>::claim_exists(ClaimDigest::Raw, claim.clone()),
+                    "duplicate claim in genesis config"
+                );
+
+                // 创世存证不占用配额
+                let bounded_claim =
+                    Pallet::<T>::insert_claim(owner, None, DEFAULT_NAMESPACE, ClaimDigest::Raw, claim.clone(), None, None)
+                        .expect("genesis claim is valid");
+                Pallet::<T>::add_to_owner(owner, &bounded_claim).expect("too many genesis claims for one owner");
+            }
+        }
     }
 
-    // 定义保留函数（非必需）， 这里不需要保留函数（存证模块不需要任何保留函数），保留函数是指在区块的不同时机执行的函数
+    // 定义保留函数（非必需），保留函数是指在区块的不同时机执行的函数
     // 模块定义里有一些特殊的函数可以在区块的某一个时间执行，这些特殊的函数定义在Hooks里面
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        // 这里没有想要定义的特殊函数，所以为空
+        // 把存储迁移到当前版本
+        fn on_runtime_upgrade() -> Weight {
+            migrations::migrate::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            migrations::pre_upgrade::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            migrations::post_upgrade::<T>()
+        }
+
+        // 区块开始时清理在该区块到期的存证和过期的承诺
+        // 每个区块到期的存证数量不超过MaxExpiriesPerBlock，过期的承诺数量不超过MaxCommitmentsPerBlock，所以权重是有上限的
+        // 每个到期的存证按最多MaxAttestersPerClaim个背书计算清理背书的权重
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let expired = Expiries::<T>::take(n);
+            let count = expired.len() as u32;
+
+            for claim in expired {
+                ClaimExpiry::<T>::remove(&claim);
+                if let Some(ClaimRecord { owner, .. }) = Self::proof_of(&claim) {
+                    Self::remove_claim(&claim, &owner);
+                    Self::deposit_event(Event::ClaimExpired(owner, claim));
+                }
+            }
+
+            let stale = CommitmentExpiries::<T>::take(n);
+            let stale_count = stale.len() as u32;
+
+            for hash in stale {
+                if let Some(commitment) = Commitments::<T>::take(&hash) {
+                    let (imbalance, _) = T::Currency::slash_reserved(&commitment.who, commitment.deposit);
+                    T::Slashed::on_unbalanced(imbalance);
+                    Self::deposit_event(Event::CommitmentExpired(commitment.who, hash));
+                }
+            }
+
+            T::WeightInfo::on_initialize(count, stale_count, T::MaxAttestersPerClaim::get())
+        }
+    }
+
+    // 无签名交易只接受带有工作量证明的create_claim_unsigned
+    // 以存证作为provides标签，交易池中相同存证的交易只保留一个
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let (digest, claim, nonce) = match call {
+                Call::create_claim_unsigned { digest, claim, nonce } => (*digest, claim, *nonce),
+                _ => return InvalidTransaction::Call.into(),
+            };
+
+            let bounded_claim = Self::check_unsigned_claim(digest, claim, nonce).map_err(|e| match e {
+                Error::<T>::ProofAlreadyExist => InvalidTransaction::Stale,
+                Error::<T>::InsufficientWork => InvalidTransaction::BadProof,
+                _ => InvalidTransaction::Call,
+            })?;
+            // 无签名提交的存证都属于模块账户，共用模块账户的配额
+            Self::ensure_quota(&Self::account_id()).map_err(|e| match e {
+                Error::<T>::BlockQuotaExceeded => InvalidTransaction::ExhaustsResources,
+                _ => InvalidTransaction::Custom(crate::extensions::QUOTA_EXCEEDED),
+            })?;
+
+            ValidTransaction::with_tag_prefix("PoeUnsigned")
+                .priority(T::UnsignedPriority::get())
+                .and_provides(bounded_claim)
+                .longevity(T::UnsignedLongevity::get())
+                .propagate(true)
+                .build()
+        }
     }
 
     // 5. 定义可调用函数（在Pallet结构体里添加）
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(0)]    
-        pub fn create_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+        // 在默认命名空间中创建存证
+        #[pallet::weight(T::WeightInfo::create_claim(claim.len() as u32))]
+        pub fn create_claim(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            expires_at: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            Self::create_claim_in(origin, DEFAULT_NAMESPACE, digest, claim, expires_at, metadata)
+        }
+
+        // 在命名空间中创建存证，注册的命名空间只有拥有者和管理员可以创建
+        #[pallet::weight(T::WeightInfo::create_claim(claim.len() as u32))]
+        pub fn create_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            expires_at: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
             // 5.1 校验发送方，并且在校验完成后获取发送方的ID
             let sender = ensure_signed(origin)?;
 
-            // 5.2 校验存证内容的Hash值是否超过最大长度
-            // 把BoundedVec尝试转成Vec<u8>，如果失败，就报错
-            let bounded_claim: BoundedVec<u8, <T as Config>::MaxClaimLength> = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone())
-                .map_err(|_| Error::<T>::ClaimTooLong)?;
+            Self::ensure_can_create_in(namespace, &sender)?;
 
-            // 5.3 如果不存在存证，就返回错误
-            ensure!(!Proofs::<T>::contains_key(&bounded_claim), Error::<T>::ProofAlreadyExist);
+            Self::do_create_claim(&sender, Some(&sender), namespace, digest, claim, expires_at, metadata)?;
 
-            // 5.4 存储记录
-            Proofs::<T>::insert(
-                &bounded_claim,
-                // 第一个元素是发送者（存证的Owner）,第二个元素是区块
-                (sender.clone(),frame_system::Pallet::<T>::block_number()),
-            );
+            Ok(().into())
+        }
+
+        // 操作员代替拥有者创建存证，押金从拥有者的账户保留
+        #[pallet::weight(T::WeightInfo::create_claim_on_behalf(claim.len() as u32))]
+        pub fn create_claim_on_behalf(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            expires_at: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            Self::create_claim_on_behalf_in(origin, owner, DEFAULT_NAMESPACE, digest, claim, expires_at, metadata)
+        }
+
+        // 操作员代替拥有者在命名空间中创建存证，注册的命名空间要求拥有者是命名空间的拥有者或管理员
+        #[pallet::weight(T::WeightInfo::create_claim_on_behalf(claim.len() as u32))]
+        pub fn create_claim_on_behalf_in(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            expires_at: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_can_act(&sender, &owner, ClaimAction::Create)?;
+            Self::ensure_can_create_in(namespace, &owner)?;
 
-            // 5.5 插入成功，触发事件
-            Self::deposit_event(Event::ClaimCreated(sender, claim));
+            let bounded_claim =
+                Self::do_create_claim(&owner, Some(&owner), namespace, digest, claim, expires_at, metadata)?;
 
+            Self::note_operator(sender, owner, bounded_claim, ClaimAction::Create);
             Ok(().into())
         }
 
-        // 吊销存证
-        #[pallet::weight(0)]
-        pub fn revoke_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+        // 吊销默认命名空间中的存证
+        #[pallet::weight(T::WeightInfo::revoke_claim(claim.len() as u32, T::MaxAttestersPerClaim::get()))]
+        pub fn revoke_claim(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            reason: ReasonCode,
+            note: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            Self::revoke_claim_in(origin, DEFAULT_NAMESPACE, digest, claim, reason, note)
+        }
+
+        // 吊销命名空间中的存证，命名空间的拥有者和管理员也可以吊销
+        #[pallet::weight(T::WeightInfo::revoke_claim(claim.len() as u32, T::MaxAttestersPerClaim::get()))]
+        pub fn revoke_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            reason: ReasonCode,
+            note: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
 
-            let bounded_claim: BoundedVec<u8, <T as Config>::MaxClaimLength> = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone())
-                .map_err(|_| Error::<T>::ClaimTooLong)?;
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
 
             // 查看存证值是否存在，只有存在才能吊销
-            let (owner, _) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
 
-            // 只有Owner才可以吊销
-            ensure!(owner == sender, Error::<T>::NotClaimOwner);
+            // 只有Owner、有权限的操作员或者命名空间的管理员才可以吊销
+            Self::ensure_can_act_in(namespace, &sender, &owner, ClaimAction::Revoke)?;
+            ensure!(!Disputes::<T>::contains_key(&bounded_claim), Error::<T>::ClaimDisputed);
 
-            Proofs::<T>::remove(&bounded_claim);
+            Self::remove_claim(&bounded_claim, &owner);
+            Self::bury_claim(&bounded_claim, Some(sender.clone()), reason, note)?;
 
-            Self::deposit_event(Event::ClaimRevoked(sender, claim));
+            Self::deposit_event(Event::ClaimRevoked(owner.clone(), bounded_claim.clone()));
+            Self::note_operator(sender, owner, bounded_claim, ClaimAction::Revoke);
             Ok(().into())
         }
 
-        // 转移存证
-        #[pallet::weight(0)]
-        pub fn transfer_claim(origin: OriginFor<T>, claim: Vec<u8>, dest: T::AccountId) -> DispatchResultWithPostInfo {
-            // 检查发送方是否合法
+        // 发起转移默认命名空间中的存证，接收方确认后才真正转移
+        // 再次发起会覆盖之前的转移请求
+        #[pallet::weight(T::WeightInfo::propose_transfer(claim.len() as u32))]
+        pub fn propose_transfer(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            dest: T::AccountId,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            Self::transfer_claim_in(origin, DEFAULT_NAMESPACE, digest, claim, dest, expires_at)
+        }
+
+        // 发起转移命名空间中的存证，命名空间的拥有者和管理员也可以发起
+        #[pallet::weight(T::WeightInfo::propose_transfer(claim.len() as u32))]
+        pub fn transfer_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            dest: T::AccountId,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            // 检查发送方是否合法
             let sender = ensure_signed(origin)?;
 
-            let bounded_claim: BoundedVec<u8, <T as Config>::MaxClaimLength> = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone())
-                .map_err(|_| Error::<T>::ClaimTooLong)?;
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
 
             // 检查存证是否存在
-            ensure!(Proofs::<T>::contains_key(&bounded_claim), Error::<T>::ClaimNotExist);
-            let (owner, _) = Proofs::<T>::get(&bounded_claim).unwrap();
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+
+            // 检查sender是否为owner、有权限的操作员或者命名空间的管理员
+            Self::ensure_can_act_in(namespace, &sender, &owner, ClaimAction::Transfer)?;
+            ensure!(!Disputes::<T>::contains_key(&bounded_claim), Error::<T>::ClaimDisputed);
+
+            if let Some(expiry) = expires_at {
+                ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+            }
+
+            PendingTransfers::<T>::insert(&bounded_claim, PendingTransfer { dest: dest.clone(), expires_at });
+
+            Self::deposit_event(Event::TransferProposed(owner.clone(), bounded_claim.clone(), dest, expires_at));
+            Self::note_operator(sender, owner, bounded_claim, ClaimAction::Transfer);
+            Ok(().into())
+        }
+
+        // 接收方确认转移，保留押金后成为新的拥有者
+        // 接收方的操作员也可以代替接收方确认，押金从接收方的账户保留
+        #[pallet::weight(T::WeightInfo::accept_transfer(claim.len() as u32))]
+        pub fn accept_transfer(origin: OriginFor<T>, digest: ClaimDigest, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+            Self::accept_transfer_in(origin, DEFAULT_NAMESPACE, digest, claim)
+        }
+
+        // 接收方确认转移命名空间中的存证
+        #[pallet::weight(T::WeightInfo::accept_transfer(claim.len() as u32))]
+        pub fn accept_transfer_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+
+            let pending = PendingTransfers::<T>::get(&bounded_claim).ok_or(Error::<T>::NoPendingTransfer)?;
+            let dest = pending.dest;
+            ensure!(
+                dest == sender || Operators::<T>::contains_key(&dest, &sender),
+                Error::<T>::NotTransferRecipient
+            );
+            Self::ensure_can_act(&sender, &dest, ClaimAction::Transfer)?;
+            if let Some(expiry) = pending.expires_at {
+                ensure!(frame_system::Pallet::<T>::block_number() < expiry, Error::<T>::TransferExpired);
+            }
+
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+            ensure!(!Disputes::<T>::contains_key(&bounded_claim), Error::<T>::ClaimDisputed);
+
+            Self::do_transfer(&bounded_claim, &owner, &dest)?;
+
+            Self::deposit_event(Event::ClaimTransfered(owner, bounded_claim.clone(), dest.clone()));
+            Self::note_operator(sender, dest, bounded_claim, ClaimAction::Transfer);
+            Ok(().into())
+        }
+
+        // 取消转移，拥有者（或其操作员）撤回或者接收方拒绝都可以取消
+        #[pallet::weight(T::WeightInfo::cancel_transfer(claim.len() as u32))]
+        pub fn cancel_transfer(origin: OriginFor<T>, digest: ClaimDigest, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+            Self::cancel_transfer_in(origin, DEFAULT_NAMESPACE, digest, claim)
+        }
+
+        // 取消转移命名空间中的存证，命名空间的拥有者和管理员也可以取消
+        #[pallet::weight(T::WeightInfo::cancel_transfer(claim.len() as u32))]
+        pub fn cancel_transfer_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+
+            let pending = PendingTransfers::<T>::get(&bounded_claim).ok_or(Error::<T>::NoPendingTransfer)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+            if sender != pending.dest {
+                Self::ensure_can_act_in(namespace, &sender, &owner, ClaimAction::Transfer)?;
+            }
+
+            PendingTransfers::<T>::remove(&bounded_claim);
+
+            Self::deposit_event(Event::TransferCancelled(owner.clone(), bounded_claim.clone(), pending.dest.clone()));
+            if sender != pending.dest {
+                Self::note_operator(sender, owner, bounded_claim, ClaimAction::Transfer);
+            }
+            Ok(().into())
+        }
+
+        // 创建批量存证：链上只保存Merkle root，每个文档通过Merkle路径证明包含在root中
+        // root占用32字节，按32字节的存证保留押金
+        #[pallet::weight(T::WeightInfo::create_batch_root())]
+        pub fn create_batch_root(origin: OriginFor<T>, root: H256, leaf_count: u32) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(leaf_count > 0, Error::<T>::EmptyBatch);
+            ensure!(!BatchRoots::<T>::contains_key(&root), Error::<T>::BatchRootAlreadyExist);
+
+            let deposit = Self::claim_deposit(H256::len_bytes());
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            BatchRoots::<T>::insert(
+                &root,
+                BatchRoot {
+                    owner: sender.clone(),
+                    created_at: frame_system::Pallet::<T>::block_number(),
+                    leaf_count,
+                    deposit,
+                },
+            );
+
+            Self::deposit_event(Event::BatchRootCreated(sender, root, leaf_count));
+            Ok(().into())
+        }
+
+        // 撤销批量存证，退还押金
+        #[pallet::weight(T::WeightInfo::revoke_batch_root())]
+        pub fn revoke_batch_root(origin: OriginFor<T>, root: H256) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let batch = BatchRoots::<T>::get(&root).ok_or(Error::<T>::BatchRootNotExist)?;
+            ensure!(batch.owner == sender, Error::<T>::NotClaimOwner);
+
+            BatchRoots::<T>::remove(&root);
+            T::Currency::unreserve(&sender, batch.deposit);
+
+            Self::deposit_event(Event::BatchRootRevoked(sender, root));
+            Ok(().into())
+        }
+
+        // 修改存证的元数据，只有拥有者可以修改，创建时间保持不变
+        #[pallet::weight(T::WeightInfo::set_claim_metadata(claim.len() as u32))]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            metadata: ClaimMetadata,
+        ) -> DispatchResultWithPostInfo {
+            Self::set_claim_metadata_in(origin, DEFAULT_NAMESPACE, digest, claim, metadata)
+        }
+
+        // 修改命名空间中存证的元数据，命名空间的拥有者和管理员也可以修改
+        #[pallet::weight(T::WeightInfo::set_claim_metadata(claim.len() as u32))]
+        pub fn set_claim_metadata_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            metadata: ClaimMetadata,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+            Self::ensure_can_act_in(namespace, &sender, &owner, ClaimAction::SetMetadata)?;
+
+            let created_at = ClaimInfos::<T>::get(&bounded_claim).map(|info| info.created_at).unwrap_or_default();
+            let info = Self::claim_info_from(metadata, created_at)?;
+            ClaimInfos::<T>::insert(&bounded_claim, info);
+
+            Self::deposit_event(Event::ClaimMetadataSet(owner.clone(), bounded_claim.clone()));
+            Self::note_operator(sender, owner, bounded_claim, ClaimAction::SetMetadata);
+            Ok(().into())
+        }
+
+        // 代替受益人创建存证：受益人在链下对create_claim_payload签名，由发送方（relayer）提交
+        // relayer支付交易费并保留押金，存证属于受益人；每个签名只能使用一次
+        #[pallet::weight(T::WeightInfo::create_claim_for(claim.len() as u32))]
+        pub fn create_claim_for(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            nonce: u64,
+            signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            ensure!(nonce == Nonces::<T>::get(&beneficiary), Error::<T>::InvalidNonce);
+            let payload = Self::create_claim_payload(&beneficiary, digest, &claim, nonce);
+            ensure!(signature.verify(&payload[..], &beneficiary), Error::<T>::InvalidSignature);
+
+            Self::do_create_claim(&beneficiary, Some(&relayer), DEFAULT_NAMESPACE, digest, claim, None, None)?;
+            Nonces::<T>::insert(&beneficiary, nonce.saturating_add(1));
+
+            Ok(().into())
+        }
+
+        // 无签名提交存证，需要附带对存证的工作量证明，见unsigned_work
+        // 存证属于模块账户，不保留押金；没有人能以模块账户签名，所以只能由ForceOrigin撤销
+        // 任何途径（包括ForceOrigin和揭示承诺）都不能转移，也不能发起争议，见do_transfer
+        #[pallet::weight(T::WeightInfo::create_claim_unsigned(claim.len() as u32))]
+        pub fn create_claim_unsigned(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            nonce: u64,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            Self::check_unsigned_claim(digest, &claim, nonce)?;
+            let bounded_claim =
+                Self::do_create_claim(&Self::account_id(), None, DEFAULT_NAMESPACE, digest, claim, None, None)?;
+            PrivilegedClaims::<T>::insert(&bounded_claim, ());
+
+            Ok(().into())
+        }
+
+        // 提交存证承诺，commitment为commitment_of(摘要算法, 存证内容, 盐)
+        // 交易池中只能看到Hash，其他人无法抢先注册同一份存证
+        // 需要保留CommitmentDeposit，揭示时退还，超过揭示窗口没有揭示则罚没
+        #[pallet::weight(T::WeightInfo::commit_claim())]
+        pub fn commit_claim(origin: OriginFor<T>, commitment: H256) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!Commitments::<T>::contains_key(&commitment), Error::<T>::CommitmentAlreadyExist);
+
+            let deposit = T::CommitmentDeposit::get();
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            CommitmentExpiries::<T>::try_mutate(Self::commitment_expiry(now), |hashes| hashes.try_push(commitment))
+                .map_err(|_| Error::<T>::TooManyCommitments)?;
+            Commitments::<T>::insert(&commitment, Commitment { who: sender.clone(), committed_at: now, deposit });
+
+            Self::deposit_event(Event::ClaimCommitted(sender, commitment));
+            Ok(().into())
+        }
+
+        // 揭示存证承诺，在承诺之后的[MinRevealDelay, MaxRevealDelay]个区块内有效
+        // 存证已经存在时，如果它的承诺（没有承诺时为创建区块）晚于本次承诺，所有权转给本次承诺的提交者
+        // 但不转移PrivilegedClaims中的存证，此时只清理承诺并退还押金
+        // 创建存证和转移存证两种情况取较大的权重
+        #[pallet::weight(T::WeightInfo::reveal_claim(claim.len() as u32)
+            .max(T::WeightInfo::reveal_claim_takeover(claim.len() as u32)))]
+        pub fn reveal_claim(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            salt: H256,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let hash = Self::commitment_of(digest, &claim, &salt);
+            let commitment = Commitments::<T>::get(&hash).ok_or(Error::<T>::CommitmentNotExist)?;
+            ensure!(commitment.who == sender, Error::<T>::NotCommitter);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let committed_at = commitment.committed_at;
+            ensure!(now >= committed_at.saturating_add(T::MinRevealDelay::get()), Error::<T>::RevealTooEarly);
+            ensure!(now <= committed_at.saturating_add(T::MaxRevealDelay::get()), Error::<T>::RevealTooLate);
+
+            Self::remove_commitment(&hash, committed_at);
+            T::Currency::unreserve(&sender, commitment.deposit);
+
+            let bounded_claim = Self::bounded_claim(digest, claim.clone())?;
+            match Self::proof_of(&bounded_claim) {
+                Some(ClaimRecord { owner, .. }) => {
+                    ensure!(committed_at < Self::claim_priority(&bounded_claim), Error::<T>::ProofAlreadyExist);
+                    ensure!(!Disputes::<T>::contains_key(&bounded_claim), Error::<T>::ClaimDisputed);
+                    // 不推翻特权操作确定的拥有者，承诺已经清理，押金已经退还
+                    if PrivilegedClaims::<T>::contains_key(&bounded_claim) {
+                        Self::deposit_event(Event::RevealSkipped(sender, bounded_claim));
+                        return Ok(().into());
+                    }
+                    Self::do_transfer(&bounded_claim, &owner, &sender)?;
+                    Self::deposit_event(Event::ClaimTransfered(owner, bounded_claim.clone(), sender.clone()));
+                },
+                None => {
+                    Self::do_create_claim(&sender, Some(&sender), DEFAULT_NAMESPACE, digest, claim, None, None)?;
+                },
+            }
+            ClaimCommittedAt::<T>::insert(&bounded_claim, committed_at);
+
+            Self::deposit_event(Event::ClaimRevealed(sender, bounded_claim));
+            Ok(().into())
+        }
+
+        // 特权强制为owner创建存证，押金从owner的账户保留
+        #[pallet::weight(T::WeightInfo::force_create_claim(claim.len() as u32))]
+        pub fn force_create_claim(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            reason: ReasonCode,
+        ) -> DispatchResultWithPostInfo {
+            Self::force_create_claim_in(origin, owner, DEFAULT_NAMESPACE, digest, claim, reason)
+        }
+
+        // 特权强制在命名空间中为owner创建存证，不检查owner在命名空间中的权限
+        #[pallet::weight(T::WeightInfo::force_create_claim(claim.len() as u32))]
+        pub fn force_create_claim_in(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            reason: ReasonCode,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                namespace == DEFAULT_NAMESPACE || Namespaces::<T>::contains_key(namespace),
+                Error::<T>::NamespaceNotExist
+            );
+            let bounded_claim = Self::do_create_claim(&owner, Some(&owner), namespace, digest, claim, None, None)?;
+            PrivilegedClaims::<T>::insert(&bounded_claim, ());
+
+            Self::deposit_event(Event::ClaimForceCreated(owner, bounded_claim, reason));
+            Ok(().into())
+        }
+
+        // 特权强制撤销存证，如欺诈存证或者法院判决，押金退还给保留押金的账户
+        #[pallet::weight(T::WeightInfo::force_revoke_claim(claim.len() as u32, T::MaxAttestersPerClaim::get()))]
+        pub fn force_revoke_claim(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            reason: ReasonCode,
+            note: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            Self::force_revoke_claim_in(origin, DEFAULT_NAMESPACE, digest, claim, reason, note)
+        }
+
+        // 特权强制撤销命名空间中的存证
+        #[pallet::weight(T::WeightInfo::force_revoke_claim(claim.len() as u32, T::MaxAttestersPerClaim::get()))]
+        pub fn force_revoke_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            reason: ReasonCode,
+            note: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+
+            Self::remove_claim(&bounded_claim, &owner);
+            Self::bury_claim(&bounded_claim, None, reason, note)?;
+
+            Self::deposit_event(Event::ClaimForceRevoked(owner, bounded_claim, reason));
+            Ok(().into())
+        }
+
+        // 特权强制转移存证，不需要拥有者发起和接收方确认，接收方需要保留押金
+        #[pallet::weight(T::WeightInfo::force_transfer_claim(claim.len() as u32))]
+        pub fn force_transfer_claim(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            dest: T::AccountId,
+            reason: ReasonCode,
+        ) -> DispatchResultWithPostInfo {
+            Self::force_transfer_claim_in(origin, DEFAULT_NAMESPACE, digest, claim, dest, reason)
+        }
+
+        // 特权强制转移命名空间中的存证
+        #[pallet::weight(T::WeightInfo::force_transfer_claim(claim.len() as u32))]
+        pub fn force_transfer_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            dest: T::AccountId,
+            reason: ReasonCode,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+
+            Self::do_transfer(&bounded_claim, &owner, &dest)?;
+            PrivilegedClaims::<T>::insert(&bounded_claim, ());
+
+            Self::deposit_event(Event::ClaimForceTransferred(owner, bounded_claim, dest, reason));
+            Ok(().into())
+        }
+
+        // 对别人的存证发起争议，保留DisputeBond作为保证金
+        // 争议期间存证不能撤销和转移，直到ArbitrationOrigin裁决
+        #[pallet::weight(T::WeightInfo::dispute_claim(claim.len() as u32))]
+        pub fn dispute_claim(origin: OriginFor<T>, digest: ClaimDigest, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+            Self::dispute_claim_in(origin, DEFAULT_NAMESPACE, digest, claim)
+        }
+
+        // 对命名空间中别人的存证发起争议
+        #[pallet::weight(T::WeightInfo::dispute_claim(claim.len() as u32))]
+        pub fn dispute_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+            ensure!(sender != owner, Error::<T>::CannotDisputeOwnClaim);
+            // 公共存证不能转移，支持争议也无法把存证转给发起者
+            ensure!(owner != Self::account_id(), Error::<T>::PublicClaim);
+            ensure!(!Disputes::<T>::contains_key(&bounded_claim), Error::<T>::ClaimDisputed);
+
+            let bond = T::DisputeBond::get();
+            T::Currency::reserve(&sender, bond).map_err(|_| Error::<T>::InsufficientDeposit)?;
+            Disputes::<T>::insert(
+                &bounded_claim,
+                Dispute { challenger: sender.clone(), bond, opened_at: frame_system::Pallet::<T>::block_number() },
+            );
+
+            Self::deposit_event(Event::ClaimDisputed(sender, owner, bounded_claim));
+            Ok(().into())
+        }
+
+        // 裁决争议：驳回时罚没发起者的保证金；支持时退还保证金，存证转给发起者
+        #[pallet::weight(T::WeightInfo::resolve_dispute(claim.len() as u32))]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            resolution: DisputeResolution,
+        ) -> DispatchResultWithPostInfo {
+            Self::resolve_dispute_in(origin, DEFAULT_NAMESPACE, digest, claim, resolution)
+        }
+
+        // 裁决命名空间中存证的争议
+        #[pallet::weight(T::WeightInfo::resolve_dispute(claim.len() as u32))]
+        pub fn resolve_dispute_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            resolution: DisputeResolution,
+        ) -> DispatchResultWithPostInfo {
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            let dispute = Disputes::<T>::take(&bounded_claim).ok_or(Error::<T>::NoDispute)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+
+            match resolution {
+                DisputeResolution::Rejected => {
+                    let (imbalance, _) = T::Currency::slash_reserved(&dispute.challenger, dispute.bond);
+                    T::Slashed::on_unbalanced(imbalance);
+                },
+                DisputeResolution::Upheld => {
+                    T::Currency::unreserve(&dispute.challenger, dispute.bond);
+                    Self::do_transfer(&bounded_claim, &owner, &dispute.challenger)?;
+                    PrivilegedClaims::<T>::insert(&bounded_claim, ());
+                    Self::deposit_event(Event::ClaimTransfered(owner, bounded_claim.clone(), dispute.challenger.clone()));
+                },
+            }
+
+            Self::deposit_event(Event::DisputeResolved(dispute.challenger, bounded_claim, resolution));
+            Ok(().into())
+        }
+
+        // 为别人的存证背书，背书不影响存证的所有权；再次背书会覆盖之前的背书内容
+        #[pallet::weight(T::WeightInfo::attest(claim.len() as u32))]
+        pub fn attest(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            payload: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            Self::attest_in(origin, DEFAULT_NAMESPACE, digest, claim, payload)
+        }
+
+        // 为命名空间中别人的存证背书
+        #[pallet::weight(T::WeightInfo::attest(claim.len() as u32))]
+        pub fn attest_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            payload: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            ensure!(Proofs::<T>::contains_key(bounded_claim.0, &bounded_claim.1), Error::<T>::ClaimNotExist);
+            let payload: BoundedVec<u8, T::MaxAttestationLength> =
+                payload.try_into().map_err(|_| Error::<T>::AttestationTooLong)?;
+
+            if !Attestations::<T>::contains_key(&bounded_claim, &sender) {
+                AttestationCount::<T>::try_mutate(&bounded_claim, |count| -> DispatchResult {
+                    ensure!(*count < T::MaxAttestersPerClaim::get(), Error::<T>::TooManyAttestations);
+                    *count += 1;
+                    Ok(())
+                })?;
+            }
+            Attestations::<T>::insert(
+                &bounded_claim,
+                &sender,
+                Attestation { payload, attested_at: frame_system::Pallet::<T>::block_number() },
+            );
+
+            Self::deposit_event(Event::ClaimAttested(sender, bounded_claim));
+            Ok(().into())
+        }
+
+        // 撤回自己的背书
+        #[pallet::weight(T::WeightInfo::withdraw_attestation(claim.len() as u32))]
+        pub fn withdraw_attestation(origin: OriginFor<T>, digest: ClaimDigest, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+            Self::withdraw_attestation_in(origin, DEFAULT_NAMESPACE, digest, claim)
+        }
+
+        // 撤回自己对命名空间中存证的背书
+        #[pallet::weight(T::WeightInfo::withdraw_attestation(claim.len() as u32))]
+        pub fn withdraw_attestation_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            ensure!(Attestations::<T>::contains_key(&bounded_claim, &sender), Error::<T>::AttestationNotExist);
+
+            Attestations::<T>::remove(&bounded_claim, &sender);
+            AttestationCount::<T>::mutate_exists(&bounded_claim, |count| {
+                *count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+            });
+
+            Self::deposit_event(Event::AttestationWithdrawn(sender, bounded_claim));
+            Ok(().into())
+        }
+
+        // 授权操作员代替自己操作存证，scope为None时允许所有操作
+        // 再次授权会覆盖之前的授权
+        #[pallet::weight(T::WeightInfo::approve_operator())]
+        pub fn approve_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            scope: Option<OperatorScope>,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(operator != sender, Error::<T>::InvalidOperator);
+            if let Some(expiry) = expires_at {
+                ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+            }
+
+            let scope = scope.unwrap_or_else(OperatorScope::all);
+            Operators::<T>::insert(&sender, &operator, OperatorApproval { scope, expires_at });
+
+            Self::deposit_event(Event::OperatorApproved(sender, operator, scope, expires_at));
+            Ok(().into())
+        }
+
+        // 取消操作员的授权
+        #[pallet::weight(T::WeightInfo::revoke_operator())]
+        pub fn revoke_operator(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Operators::<T>::contains_key(&sender, &operator), Error::<T>::OperatorNotExist);
+            Operators::<T>::remove(&sender, &operator);
 
-            // 检查sender是否为owner
-            ensure!(sender == owner, Error::<T>::NotClaimOwner);
+            Self::deposit_event(Event::OperatorRevoked(sender, operator));
+            Ok(().into())
+        }
+
+        // 注册命名空间，保留押金，发送方成为命名空间的拥有者
+        #[pallet::weight(T::WeightInfo::create_namespace())]
+        pub fn create_namespace(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            // 默认命名空间不需要注册，注册的ID从1开始
+            let id = NextNamespaceId::<T>::get().max(DEFAULT_NAMESPACE + 1);
+            let next = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+            let deposit = T::NamespaceDeposit::get();
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            Namespaces::<T>::insert(id, Namespace { owner: sender.clone(), admins: Default::default(), deposit });
+            NextNamespaceId::<T>::put(next);
+
+            Self::deposit_event(Event::NamespaceCreated(sender, id));
+            Ok(().into())
+        }
+
+        // 添加命名空间管理员，只有命名空间的拥有者可以添加
+        #[pallet::weight(T::WeightInfo::add_namespace_admin())]
+        pub fn add_namespace_admin(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            admin: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            Namespaces::<T>::try_mutate(namespace, |ns| -> DispatchResult {
+                let ns = ns.as_mut().ok_or(Error::<T>::NamespaceNotExist)?;
+                ensure!(ns.owner == sender, Error::<T>::NotNamespaceOwner);
+                ensure!(!ns.admins.contains(&admin), Error::<T>::AlreadyNamespaceAdmin);
+                ns.admins.try_push(admin.clone()).map_err(|_| Error::<T>::TooManyNamespaceAdmins)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::NamespaceAdminAdded(namespace, admin));
+            Ok(().into())
+        }
+
+        // 删除命名空间管理员，只有命名空间的拥有者可以删除
+        #[pallet::weight(T::WeightInfo::remove_namespace_admin())]
+        pub fn remove_namespace_admin(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            admin: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            Namespaces::<T>::try_mutate(namespace, |ns| -> DispatchResult {
+                let ns = ns.as_mut().ok_or(Error::<T>::NamespaceNotExist)?;
+                ensure!(ns.owner == sender, Error::<T>::NotNamespaceOwner);
+                let index = ns.admins.iter().position(|a| a == &admin).ok_or(Error::<T>::NamespaceAdminNotExist)?;
+                ns.admins.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::NamespaceAdminRemoved(namespace, admin));
+            Ok(().into())
+        }
+
+        // 用新版本取代存证，例如合同修订后的摘要：旧存证保留，新存证属于旧存证的拥有者，并记录前后版本的链接
+        // 每个存证只能被取代一次，新版本可以继续被取代
+        #[pallet::weight(T::WeightInfo::supersede_claim(claim.len().max(new_claim.len()) as u32))]
+        pub fn supersede_claim(
+            origin: OriginFor<T>,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            new_digest: ClaimDigest,
+            new_claim: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            Self::supersede_claim_in(origin, DEFAULT_NAMESPACE, digest, claim, new_digest, new_claim)
+        }
+
+        // 用新版本取代命名空间中的存证，新版本创建在同一个命名空间中
+        #[pallet::weight(T::WeightInfo::supersede_claim(claim.len().max(new_claim.len()) as u32))]
+        pub fn supersede_claim_in(
+            origin: OriginFor<T>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            new_digest: ClaimDigest,
+            new_claim: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+            let owner = Self::proof_of(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?.owner;
+
+            // 取代相当于撤销旧版本并在命名空间中创建新版本，需要撤销和创建的权限
+            Self::ensure_can_act_in(namespace, &sender, &owner, ClaimAction::Revoke)?;
+            Self::ensure_can_create_in(namespace, &sender)?;
+            ensure!(!Disputes::<T>::contains_key(&bounded_claim), Error::<T>::ClaimDisputed);
+            ensure!(
+                Versions::<T>::get(&bounded_claim).map_or(true, |version| version.next.is_none()),
+                Error::<T>::ClaimAlreadySuperseded
+            );
+
+            let new_bounded_claim =
+                Self::do_create_claim(&owner, Some(&owner), namespace, new_digest, new_claim, None, None)?;
 
-            Proofs::<T>::insert(&bounded_claim, (dest, frame_system::Pallet::<T>::block_number()));
+            Versions::<T>::mutate(&bounded_claim, |version| {
+                let version = version.get_or_insert(ClaimVersion { previous: None, next: None });
+                version.next = Some(new_bounded_claim.clone());
+            });
+            Versions::<T>::insert(
+                &new_bounded_claim,
+                ClaimVersion { previous: Some(bounded_claim.clone()), next: None },
+            );
 
+            Self::deposit_event(Event::ClaimSuperseded(owner.clone(), bounded_claim.clone(), new_bounded_claim));
+            Self::note_operator(sender, owner, bounded_claim, ClaimAction::Revoke);
             Ok(().into())
         }
     }
-}
\ No newline at end of file
+
+    impl<T: Config> Pallet<T> {
+        // 为owner创建存证，所有创建存证的调用都经过这里，占用owner的配额和当前区块的配额
+        pub(crate) fn do_create_claim(
+            owner: &T::AccountId,
+            depositor: Option<&T::AccountId>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            expires_at: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> Result<ClaimOf<T>, DispatchError> {
+            Self::ensure_quota(owner)?;
+            let bounded_claim = Self::insert_claim(owner, depositor, namespace, digest, claim, expires_at, metadata)?;
+            Self::use_quota(owner);
+            Ok(bounded_claim)
+        }
+
+        // 写入存证：校验存证内容和元数据，从depositor保留押金，写入各索引并触发ClaimCreated事件
+        // depositor为None时是无签名提交的公共存证，不保留押金，也不加入拥有者的存证索引
+        fn insert_claim(
+            owner: &T::AccountId,
+            depositor: Option<&T::AccountId>,
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+            expires_at: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> Result<ClaimOf<T>, DispatchError> {
+            // 5.2 校验存证内容的Hash值是否超过最大长度，以及是否符合摘要算法的长度
+            if let Some(len) = digest.digest_len() {
+                ensure!(claim.len() == len, Error::<T>::InvalidDigestLength);
+            }
+            let bounded_claim = Self::bounded_claim_in(namespace, digest, claim)?;
+
+            // 5.3 如果不存在存证，就返回错误
+            ensure!(!Proofs::<T>::contains_key(bounded_claim.0, &bounded_claim.1), Error::<T>::ProofAlreadyExist);
+            Self::ensure_not_tombstoned(&bounded_claim)?;
+
+            // 5.4 校验元数据长度，同时记录创建时间
+            let now = T::Time::now().as_secs();
+            let info = Self::claim_info_from(metadata.unwrap_or_default(), now)?;
+            ClaimInfos::<T>::insert(&bounded_claim, info);
+            ClaimsByMoment::<T>::insert(Self::moment_key(namespace, now), &bounded_claim.1, ());
+
+            // 5.5 如果设置了到期区块，加入到期索引
+            if let Some(expiry) = expires_at {
+                ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+                Expiries::<T>::try_mutate(expiry, |claims| claims.try_push(bounded_claim.clone()))
+                    .map_err(|_| Error::<T>::TooManyExpiries)?;
+                ClaimExpiry::<T>::insert(&bounded_claim, expiry);
+            }
+
+            // 5.6 按存证长度保留押金，并加入拥有者的存证索引
+            if let Some(depositor) = depositor {
+                let deposit = Self::claim_deposit((bounded_claim.1).1.len());
+                T::Currency::reserve(depositor, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+                ClaimDeposits::<T>::insert(&bounded_claim, (depositor.clone(), deposit));
+
+                Self::add_to_owner(owner, &bounded_claim)?;
+            }
+
+            // 5.7 记录所有权
+            Self::record_provenance(&bounded_claim, owner, TransferKind::Created)?;
+
+            // 5.8 存储记录
+            Proofs::<T>::insert(
+                bounded_claim.0,
+                &bounded_claim.1,
+                ClaimRecord {
+                    owner: owner.clone(),
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                    moment: now,
+                },
+            );
+
+            // 5.9 插入成功，触发事件
+            Self::deposit_event(Event::ClaimCreated(owner.clone(), bounded_claim.clone(), now));
+
+            Ok(bounded_claim)
+        }
+
+        // 为被撤销的存证留下墓碑
+        pub(crate) fn bury_claim(
+            claim: &ClaimOf<T>,
+            revoker: Option<T::AccountId>,
+            reason: ReasonCode,
+            note: Vec<u8>,
+        ) -> DispatchResult {
+            let note = note.try_into().map_err(|_| Error::<T>::NoteTooLong)?;
+            Revoked::<T>::insert(
+                claim,
+                Tombstone { revoker, revoked_at: frame_system::Pallet::<T>::block_number(), reason, note },
+            );
+            Ok(())
+        }
+
+        // ForbidReregistration打开时，已被撤销的存证不能重新创建
+        fn ensure_not_tombstoned(claim: &ClaimOf<T>) -> Result<(), Error<T>> {
+            if T::ForbidReregistration::get() {
+                ensure!(!Revoked::<T>::contains_key(claim), Error::<T>::ClaimTombstoned);
+            }
+            Ok(())
+        }
+
+        // 查询存证的状态：存在、已被撤销或者从未存在
+        pub fn claim_status(digest: ClaimDigest, claim: Vec<u8>) -> ClaimStatus<T> {
+            let bounded_claim = match Self::bounded_claim(digest, claim) {
+                Ok(bounded_claim) => bounded_claim,
+                Err(_) => return ClaimStatus::NeverExisted,
+            };
+            if let Some(record) = Self::proof_of(&bounded_claim) {
+                return ClaimStatus::Active(record.owner, record.block_number)
+            }
+            Revoked::<T>::get(&bounded_claim).map_or(ClaimStatus::NeverExisted, ClaimStatus::Revoked)
+        }
+
+        // 检查who是否可以对owner的存证执行action：who是拥有者本人，或者是未到期且有权限的操作员
+        fn ensure_can_act(who: &T::AccountId, owner: &T::AccountId, action: ClaimAction) -> DispatchResult {
+            if who == owner {
+                return Ok(())
+            }
+
+            let approval = Operators::<T>::get(owner, who).ok_or(Error::<T>::NotClaimOwner)?;
+            if let Some(expiry) = approval.expires_at {
+                ensure!(frame_system::Pallet::<T>::block_number() < expiry, Error::<T>::OperatorExpired);
+            }
+            ensure!(approval.scope.allows(action), Error::<T>::OperatorNotPermitted);
+            Ok(())
+        }
+
+        // 检查账户的配额和当前区块的配额是否还有剩余
+        // 账户的配额是滑动窗口：只计算最近QuotaWindow个区块（包括当前区块）内创建的存证
+        pub fn ensure_quota(who: &T::AccountId) -> Result<(), Error<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let (block, count) = BlockClaims::<T>::get();
+            ensure!(block != now || count < T::MaxClaimsPerBlock::get(), Error::<T>::BlockQuotaExceeded);
+
+            let used = QuotaUsages::<T>::get(who).iter().filter(|created| Self::in_quota_window(**created, now)).count();
+            ensure!((used as u32) < T::MaxClaimsPerWindow::get(), Error::<T>::QuotaExceeded);
+            Ok(())
+        }
+
+        // 记录一次配额使用，同时删除已经滑出窗口的记录
+        fn use_quota(who: &T::AccountId) {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            BlockClaims::<T>::mutate(|(block, count)| {
+                if *block != now {
+                    *block = now;
+                    *count = 0;
+                }
+                *count = count.saturating_add(1);
+            });
+
+            QuotaUsages::<T>::mutate(who, |usage| {
+                usage.retain(|created| Self::in_quota_window(*created, now));
+                // ensure_quota已经检查过窗口内的记录少于MaxClaimsPerWindow
+                let _ = usage.try_push(now);
+            });
+        }
+
+        // 在created区块创建的存证在now区块是否还占用配额
+        fn in_quota_window(created: T::BlockNumber, now: T::BlockNumber) -> bool {
+            now < created.saturating_add(T::QuotaWindow::get())
+        }
+
+        // 账户是否为命名空间的拥有者或管理员
+        pub fn is_namespace_admin(namespace: NamespaceId, who: &T::AccountId) -> bool {
+            Namespaces::<T>::get(namespace).map_or(false, |ns| &ns.owner == who || ns.admins.contains(who))
+        }
+
+        // 检查who是否可以在命名空间中创建存证：默认命名空间所有账户都可以，注册的命名空间只有拥有者和管理员可以
+        fn ensure_can_create_in(namespace: NamespaceId, who: &T::AccountId) -> DispatchResult {
+            if namespace == DEFAULT_NAMESPACE {
+                return Ok(())
+            }
+            ensure!(Namespaces::<T>::contains_key(namespace), Error::<T>::NamespaceNotExist);
+            ensure!(Self::is_namespace_admin(namespace, who), Error::<T>::NoNamespacePermission);
+            Ok(())
+        }
+
+        // 检查who是否可以对命名空间中owner的存证执行action：注册的命名空间的拥有者和管理员可以操作其中所有的存证
+        fn ensure_can_act_in(
+            namespace: NamespaceId,
+            who: &T::AccountId,
+            owner: &T::AccountId,
+            action: ClaimAction,
+        ) -> DispatchResult {
+            if namespace != DEFAULT_NAMESPACE && Self::is_namespace_admin(namespace, who) {
+                return Ok(())
+            }
+            Self::ensure_can_act(who, owner, action)
+        }
+
+        // 操作员代替拥有者操作时，额外触发一个同时记录操作员和拥有者的事件
+        fn note_operator(operator: T::AccountId, owner: T::AccountId, claim: ClaimOf<T>, action: ClaimAction) {
+            if operator != owner {
+                Self::deposit_event(Event::OperatorActed(operator, owner, claim, action));
+            }
+        }
+
+        // 删除存证及其相关的存储项（所有权记录除外），并退还押金中HistoryDeposit以外的部分
+        pub(crate) fn remove_claim(claim: &ClaimOf<T>, owner: &T::AccountId) {
+            Proofs::<T>::remove(claim.0, &claim.1);
+
+            if let Some(info) = ClaimInfos::<T>::take(claim) {
+                ClaimsByMoment::<T>::remove(Self::moment_key(claim.0, info.created_at), &claim.1);
+            }
+
+            PendingTransfers::<T>::remove(claim);
+
+            // 所有权记录只追加不删除，存证被撤销或到期后仍然保留，重新创建时继续追加
+
+            ClaimCommittedAt::<T>::remove(claim);
+
+            PrivilegedClaims::<T>::remove(claim);
+
+            // 背书者数量不超过MaxAttestersPerClaim，一次就能清理完
+            let _ = Attestations::<T>::clear_prefix(claim, T::MaxAttestersPerClaim::get(), None);
+            AttestationCount::<T>::remove(claim);
+
+            // 存证被强制撤销或到期时，争议随之结束，退还保证金
+            if let Some(dispute) = Disputes::<T>::take(claim) {
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+            }
+
+            Self::remove_from_owner(owner, claim);
+
+            // 墓碑和所有权记录在存证删除后仍然保留，由押金中的HistoryDeposit支付
+            // 同一存证之前保留的押金由这一次的代替，退还给原来的账户
+            if let Some((depositor, deposit)) = ClaimDeposits::<T>::take(claim) {
+                let retained = deposit.min(T::HistoryDeposit::get());
+                T::Currency::unreserve(&depositor, deposit.saturating_sub(retained));
+                if let Some((previous, previous_deposit)) = HistoryDeposits::<T>::take(claim) {
+                    T::Currency::unreserve(&previous, previous_deposit);
+                }
+                HistoryDeposits::<T>::insert(claim, (depositor, retained));
+            }
+
+            if let Some(expiry) = ClaimExpiry::<T>::take(claim) {
+                Expiries::<T>::mutate_exists(expiry, |claims| {
+                    if let Some(list) = claims {
+                        list.retain(|c| c != claim);
+                    }
+                    if claims.as_ref().map_or(false, |list| list.is_empty()) {
+                        *claims = None;
+                    }
+                });
+            }
+
+            // 从版本链中摘除，前后两个版本直接相连
+            if let Some(version) = Versions::<T>::take(claim) {
+                if let Some(previous) = &version.previous {
+                    Self::relink_version(previous, |v| v.next = version.next.clone());
+                }
+                if let Some(next) = &version.next {
+                    Self::relink_version(next, |v| v.previous = version.previous.clone());
+                }
+            }
+        }
+
+        // 修改存证的版本链接，前后都没有版本时删除
+        fn relink_version(claim: &ClaimOf<T>, f: impl FnOnce(&mut ClaimVersion<T>)) {
+            Versions::<T>::mutate_exists(claim, |version| {
+                if let Some(v) = version {
+                    f(v);
+                    if v.previous.is_none() && v.next.is_none() {
+                        *version = None;
+                    }
+                }
+            });
+        }
+
+        // 把存证转给新的拥有者：新拥有者保留押金，余额不足则转移失败；之后退还原来保留押金的账户
+        // 公共存证没有押金，不能转给任何账户
+        pub(crate) fn do_transfer(claim: &ClaimOf<T>, from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+            ensure!(from != &Self::account_id(), Error::<T>::PublicClaim);
+            if let Some((depositor, deposit)) = ClaimDeposits::<T>::get(claim) {
+                T::Currency::reserve(to, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+                T::Currency::unreserve(&depositor, deposit);
+                ClaimDeposits::<T>::insert(claim, (to.clone(), deposit));
+            }
+
+            Self::remove_from_owner(from, claim);
+            Self::add_to_owner(to, claim)?;
+            Self::record_provenance(claim, to, TransferKind::Transferred)?;
+
+            Proofs::<T>::insert(
+                claim.0,
+                &claim.1,
+                ClaimRecord {
+                    owner: to.clone(),
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                    moment: T::Time::now().as_secs(),
+                },
+            );
+            PendingTransfers::<T>::remove(claim);
+            PrivilegedClaims::<T>::remove(claim);
+
+            Ok(())
+        }
+
+        // 把存证加入拥有者的存证索引，超过MaxClaimsPerAccount则报错
+        fn add_to_owner(owner: &T::AccountId, claim: &ClaimOf<T>) -> DispatchResult {
+            ClaimCount::<T>::try_mutate(owner, |count| -> DispatchResult {
+                ensure!(*count < T::MaxClaimsPerAccount::get(), Error::<T>::TooManyClaims);
+                *count += 1;
+                Ok(())
+            })?;
+            ClaimsByOwner::<T>::insert(owner, claim, ());
+            Ok(())
+        }
+
+        // 把存证从拥有者的存证索引中删除
+        fn remove_from_owner(owner: &T::AccountId, claim: &ClaimOf<T>) {
+            if ClaimsByOwner::<T>::take(owner, claim).is_some() {
+                ClaimCount::<T>::mutate_exists(owner, |count| {
+                    *count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+                });
+            }
+        }
+
+        // 追加一条所有权记录，记录满了之后按ProvenancePruning处理
+        fn record_provenance(claim: &ClaimOf<T>, owner: &T::AccountId, kind: TransferKind) -> DispatchResult {
+            let record = ProvenanceRecord {
+                owner: owner.clone(),
+                acquired_at: frame_system::Pallet::<T>::block_number(),
+                kind,
+            };
+            Provenance::<T>::try_mutate(claim, |history| {
+                if history.len() as u32 >= T::MaxProvenanceLength::get() {
+                    match T::ProvenancePruning::get() {
+                        ProvenancePruning::RejectWhenFull => return Err(Error::<T>::ProvenanceFull.into()),
+                        // 存证被撤销后重新创建时会有多条创建记录，最后一条是当前存证的创建记录，删除它之外最早的一条
+                        ProvenancePruning::DropOldest => {
+                            let current = history.iter().rposition(|r| r.kind == TransferKind::Created);
+                            let oldest = if current == Some(0) && history.len() > 1 { 1 } else { 0 };
+                            history.remove(oldest);
+                        },
+                    }
+                }
+                history.try_push(record).map_err(|_| Error::<T>::ProvenanceFull.into())
+            })
+        }
+
+        // 查询存证完整的所有权记录，按时间顺序排列，包括存证被撤销或到期之前的记录
+        pub fn provenance_of(claim: &ClaimOf<T>) -> Vec<ProvenanceRecord<T::AccountId, T::BlockNumber>> {
+            Provenance::<T>::get(claim).into_inner()
+        }
+
+        // 分页查询命名空间中在[from, to)时间范围内创建的存证，时间为Unix时间戳（秒），按创建时间排列
+        // cursor为上一页最后一个存证及其创建时间，每页最多limit个，limit不超过MaxClaimsPerAccount
+        // 通过ClaimsByMoment索引只遍历查询的时间范围，最多读取limit个存证
+        // 从旧版本迁移过来的存证无法知道创建时间，创建时间记为0
+        pub fn claims_created_between(
+            namespace: NamespaceId,
+            from: u64,
+            to: u64,
+            cursor: Option<(u64, ClaimBody<T>)>,
+            limit: u32,
+        ) -> Vec<(u64, ClaimBody<T>)> {
+            let limit = limit.min(T::MaxClaimsPerAccount::get()) as usize;
+            let start = match cursor {
+                Some((moment, claim)) if moment >= from =>
+                    ClaimsByMoment::<T>::hashed_key_for(Self::moment_key(namespace, moment), claim),
+                _ => {
+                    let mut key = ClaimsByMoment::<T>::final_prefix().to_vec();
+                    Self::moment_key(namespace, from).encode_to(&mut key);
+                    key
+                },
+            };
+            ClaimsByMoment::<T>::iter_from(start)
+                .map(|((id, moment), claim, ())| (id, u64::from_be_bytes(moment), claim))
+                .take_while(|(id, moment, _)| *id == namespace && *moment < to)
+                .take(limit)
+                .map(|(_, moment, claim)| (moment, claim))
+                .collect()
+        }
+
+        // 供Runtime API使用的claims_created_between，只查询默认命名空间
+        pub fn created_claims(
+            from: u64,
+            to: u64,
+            cursor: Option<(u64, ClaimDigest, Vec<u8>)>,
+            limit: u32,
+        ) -> Vec<(u64, ClaimDigest, Vec<u8>)> {
+            let cursor = cursor.and_then(|(moment, digest, claim)| {
+                Self::bounded_claim(digest, claim).ok().map(|(_, claim)| (moment, claim))
+            });
+            Self::claims_created_between(DEFAULT_NAMESPACE, from, to, cursor, limit)
+                .into_iter()
+                .map(|(moment, (digest, claim))| (moment, digest, claim.into_inner()))
+                .collect()
+        }
+
+        // ClaimsByMoment的第一个Key，创建时间用大端字节，按字节排序即按时间排序
+        pub(crate) fn moment_key(namespace: NamespaceId, moment: u64) -> (NamespaceId, [u8; 8]) {
+            (namespace, moment.to_be_bytes())
+        }
+
+        // 查询存证所在的完整版本链，从最早的版本到最新的版本排列，没有版本链接时只包含存证本身
+        pub fn version_chain(claim: &ClaimOf<T>) -> Vec<ClaimOf<T>> {
+            let mut chain = sp_std::vec![claim.clone()];
+            let mut cursor = Versions::<T>::get(claim).and_then(|version| version.previous);
+            while let Some(previous) = cursor {
+                cursor = Versions::<T>::get(&previous).and_then(|version| version.previous);
+                chain.push(previous);
+            }
+            chain.reverse();
+
+            let mut cursor = Versions::<T>::get(claim).and_then(|version| version.next);
+            while let Some(next) = cursor {
+                cursor = Versions::<T>::get(&next).and_then(|version| version.next);
+                chain.push(next);
+            }
+            chain
+        }
+
+        // 查询存证的最新版本，没有被取代时返回存证本身
+        pub fn latest_version(claim: &ClaimOf<T>) -> ClaimOf<T> {
+            let mut latest = claim.clone();
+            while let Some(next) = Versions::<T>::get(&latest).and_then(|version| version.next) {
+                latest = next;
+            }
+            latest
+        }
+
+        // 校验文档摘要leaf是否包含在批量存证root中，proof为从叶子节点到root的兄弟节点
+        // 校验通过则返回批量存证的区块，即文档的存证时间
+        pub fn verify_inclusion(root: &H256, leaf: &H256, proof: &[H256]) -> Option<T::BlockNumber> {
+            let batch = BatchRoots::<T>::get(root)?;
+            merkle::verify_proof(root, leaf, proof).then(|| batch.created_at)
+        }
+
+        // 查询存证的拥有者和区块
+        pub fn proof_of(claim: &ClaimOf<T>) -> Option<ClaimRecord<T::AccountId, T::BlockNumber>> {
+            Proofs::<T>::get(claim.0, &claim.1)
+        }
+
+        // 以下三个函数供Runtime API使用，参数和返回值都是不带长度限制的类型，只查询默认命名空间
+        // 查询存证的拥有者和区块，存证内容超过最大长度时返回None
+        pub fn get_claim(digest: ClaimDigest, claim: Vec<u8>) -> Option<(T::AccountId, T::BlockNumber)> {
+            Self::bounded_claim(digest, claim)
+                .ok()
+                .and_then(|key| Self::proof_of(&key))
+                .map(|record| (record.owner, record.block_number))
+        }
+
+        // 存证是否存在
+        pub fn claim_exists(digest: ClaimDigest, claim: Vec<u8>) -> bool {
+            Self::bounded_claim(digest, claim).map_or(false, |key| Proofs::<T>::contains_key(key.0, &key.1))
+        }
+
+        // 分页查询账户拥有的存证，cursor无效时从第一页开始
+        pub fn owned_claims(
+            owner: T::AccountId,
+            cursor: Option<(ClaimDigest, Vec<u8>)>,
+            limit: u32,
+        ) -> Vec<(ClaimDigest, Vec<u8>)> {
+            let limit = limit.min(T::MaxClaimsPerAccount::get()) as usize;
+            let cursor = cursor.and_then(|(digest, claim)| Self::bounded_claim(digest, claim).ok());
+            // 先过滤掉其他命名空间的存证再取limit个，保证不是最后一页时返回完整的一页
+            Self::claims_of_from(&owner, cursor)
+                .filter(|(namespace, _)| *namespace == DEFAULT_NAMESPACE)
+                .take(limit)
+                .map(|(_, (digest, claim))| (digest, claim.into_inner()))
+                .collect()
+        }
+
+        // 分页查询账户拥有的存证，每页最多limit个
+        // cursor为上一页的最后一个存证，第一页传None；返回的存证少于limit说明已经是最后一页
+        pub fn claims_of(owner: &T::AccountId, cursor: Option<ClaimOf<T>>, limit: u32) -> Vec<ClaimOf<T>> {
+            let limit = limit.min(T::MaxClaimsPerAccount::get()) as usize;
+            Self::claims_of_from(owner, cursor).take(limit).collect()
+        }
+
+        // 从cursor之后开始遍历账户拥有的存证，cursor为None时从头开始
+        fn claims_of_from(owner: &T::AccountId, cursor: Option<ClaimOf<T>>) -> impl Iterator<Item = ClaimOf<T>> {
+            match cursor {
+                Some(last) => ClaimsByOwner::<T>::iter_key_prefix_from(
+                    owner,
+                    ClaimsByOwner::<T>::hashed_key_for(owner, &last),
+                ),
+                None => ClaimsByOwner::<T>::iter_key_prefix(owner),
+            }
+        }
+
+        // 把默认命名空间中的存证内容转成存储Key，存证内容超过最大长度则报错
+        pub fn bounded_claim(digest: ClaimDigest, claim: Vec<u8>) -> Result<ClaimOf<T>, Error<T>> {
+            Self::bounded_claim_in(DEFAULT_NAMESPACE, digest, claim)
+        }
+
+        // 把命名空间中的存证内容转成存储Key
+        pub fn bounded_claim_in(
+            namespace: NamespaceId,
+            digest: ClaimDigest,
+            claim: Vec<u8>,
+        ) -> Result<ClaimOf<T>, Error<T>> {
+            let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim)
+                .map_err(|_| Error::<T>::ClaimTooLong)?;
+            Ok((namespace, (digest, bounded_claim)))
+        }
+
+        // 校验元数据的长度，转换成链上保存的存证信息
+        fn claim_info_from(metadata: ClaimMetadata, created_at: u64) -> Result<ClaimInfo<T>, Error<T>> {
+            Ok(ClaimInfo {
+                description: metadata.description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?,
+                content_type: metadata.content_type.try_into().map_err(|_| Error::<T>::ContentTypeTooLong)?,
+                uri: metadata
+                    .uri
+                    .map(|uri| uri.try_into().map_err(|_| Error::<T>::UriTooLong))
+                    .transpose()?,
+                created_at,
+            })
+        }
+
+        // 模块账户，无签名提交的存证属于这个账户
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        // 无签名提交存证的工作量Hash：blake2_256(摘要算法 ++ 存证内容 ++ nonce)
+        // 提交者不断递增nonce，直到Hash的前导0比特数达到UnsignedDifficulty
+        pub fn unsigned_work(digest: ClaimDigest, claim: &[u8], nonce: u64) -> H256 {
+            H256(blake2_256(&(digest, claim, nonce).encode()))
+        }
+
+        // 工作量Hash是否达到难度要求
+        pub fn meets_difficulty(work: &H256) -> bool {
+            let mut zeros = 0;
+            for byte in work.as_bytes() {
+                zeros += byte.leading_zeros();
+                if *byte != 0 {
+                    break
+                }
+            }
+            zeros >= T::UnsignedDifficulty::get()
+        }
+
+        // 交易池和执行交易时都要做的校验：存证内容合法、尚未存在，并且工作量证明达到难度
+        fn check_unsigned_claim(digest: ClaimDigest, claim: &[u8], nonce: u64) -> Result<ClaimOf<T>, Error<T>> {
+            if let Some(len) = digest.digest_len() {
+                ensure!(claim.len() == len, Error::<T>::InvalidDigestLength);
+            }
+            let bounded_claim = Self::bounded_claim(digest, claim.to_vec())?;
+            ensure!(!Proofs::<T>::contains_key(bounded_claim.0, &bounded_claim.1), Error::<T>::ProofAlreadyExist);
+            Self::ensure_not_tombstoned(&bounded_claim)?;
+            ensure!(Self::meets_difficulty(&Self::unsigned_work(digest, claim, nonce)), Error::<T>::InsufficientWork);
+            Ok(bounded_claim)
+        }
+
+        // 存证承诺：blake2_256(摘要算法 ++ 存证内容 ++ 盐)
+        pub fn commitment_of(digest: ClaimDigest, claim: &[u8], salt: &H256) -> H256 {
+            H256(blake2_256(&(digest, claim, salt).encode()))
+        }
+
+        // 在committed_at提交的承诺在哪个区块开始时被清理
+        fn commitment_expiry(committed_at: T::BlockNumber) -> T::BlockNumber {
+            committed_at.saturating_add(T::MaxRevealDelay::get()).saturating_add(1u32.into())
+        }
+
+        // 删除承诺及其在过期索引中的记录
+        fn remove_commitment(hash: &H256, committed_at: T::BlockNumber) {
+            Commitments::<T>::remove(hash);
+            CommitmentExpiries::<T>::mutate_exists(Self::commitment_expiry(committed_at), |hashes| {
+                if let Some(list) = hashes {
+                    list.retain(|h| h != hash);
+                }
+                if hashes.as_ref().map_or(false, |list| list.is_empty()) {
+                    *hashes = None;
+                }
+            });
+        }
+
+        // 存证的先后顺序：通过揭示创建的存证以承诺区块为准，否则以当前存证的创建区块为准
+        fn claim_priority(claim: &ClaimOf<T>) -> T::BlockNumber {
+            ClaimCommittedAt::<T>::get(claim)
+                .or_else(|| {
+                    Provenance::<T>::get(claim)
+                        .iter()
+                        .rev()
+                        .find(|record| record.kind == TransferKind::Created)
+                        .map(|record| record.acquired_at)
+                })
+                .unwrap_or_else(frame_system::Pallet::<T>::block_number)
+        }
+
+        // create_claim_for中受益人需要签名的内容
+        // 包含创世区块Hash，其他链上的签名（nonce同样从0开始）不能在本链重放
+        pub fn create_claim_payload(
+            beneficiary: &T::AccountId,
+            digest: ClaimDigest,
+            claim: &[u8],
+            nonce: u64,
+        ) -> Vec<u8> {
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+            (b"poe/create_claim_for", genesis_hash, beneficiary, digest, claim, nonce).encode()
+        }
+
+        // 计算指定长度的存证需要保留的押金：基础押金 + 每字节押金 * 长度
+        pub fn claim_deposit(len: usize) -> BalanceOf<T> {
+            T::ClaimDepositPerByte::get()
+                .saturating_mul((len as u32).into())
+                .saturating_add(T::ClaimDepositBase::get())
+        }
+    }
+}
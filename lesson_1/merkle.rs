@@ -0,0 +1,82 @@
+//! 批量存证使用的Merkle树
+//!
+//! 叶子节点 = blake2_256(0x00 ++ 文档摘要)，中间节点 = blake2_256(0x01 ++ 较小的子节点 ++ 较大的子节点)。
+//! 子节点排序之后再哈希，所以证明只需要按从下到上的顺序给出兄弟节点，不需要记录左右位置。
+//! 某一层的节点数为奇数时，最后一个节点直接提升到上一层。
+
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+#[cfg(feature = "std")]
+use sp_std::vec::Vec;
+
+// 叶子节点的哈希，加前缀0x00与中间节点区分
+pub fn leaf_hash(leaf: &H256) -> H256 {
+	let mut data = [0u8; 33];
+	data[1..].copy_from_slice(leaf.as_bytes());
+	H256(blake2_256(&data))
+}
+
+// 中间节点的哈希，两个子节点先排序
+pub fn node_hash(a: &H256, b: &H256) -> H256 {
+	let (left, right) = if a <= b { (a, b) } else { (b, a) };
+	let mut data = [0u8; 65];
+	data[0] = 1;
+	data[1..33].copy_from_slice(left.as_bytes());
+	data[33..].copy_from_slice(right.as_bytes());
+	H256(blake2_256(&data))
+}
+
+// 校验叶子节点和Merkle路径能否得到root
+pub fn verify_proof(root: &H256, leaf: &H256, proof: &[H256]) -> bool {
+	let computed = proof.iter().fold(leaf_hash(leaf), |node, sibling| node_hash(&node, sibling));
+	&computed == root
+}
+
+// 链下构造Merkle树，用于计算root和生成每个叶子节点的证明
+#[cfg(feature = "std")]
+pub struct MerkleTree {
+	// 第一层是叶子节点，最后一层是root
+	layers: Vec<Vec<H256>>,
+}
+
+#[cfg(feature = "std")]
+impl MerkleTree {
+	pub fn new(leaves: &[H256]) -> Self {
+		let mut layers = vec![leaves.iter().map(leaf_hash).collect::<Vec<_>>()];
+		while let Some(layer) = layers.last().filter(|layer| layer.len() > 1) {
+			let next = layer
+				.chunks(2)
+				.map(|pair| match pair {
+					[a, b] => node_hash(a, b),
+					[a] => *a,
+					_ => unreachable!("chunks(2) yields one or two nodes; qed"),
+				})
+				.collect();
+			layers.push(next);
+		}
+		MerkleTree { layers }
+	}
+
+	// 没有叶子节点时返回None
+	pub fn root(&self) -> Option<H256> {
+		self.layers.last().and_then(|layer| layer.first().copied())
+	}
+
+	// 第index个叶子节点的证明
+	pub fn proof(&self, index: usize) -> Option<Vec<H256>> {
+		if index >= self.layers[0].len() {
+			return None
+		}
+
+		let mut proof = Vec::new();
+		let mut index = index;
+		for layer in &self.layers[..self.layers.len() - 1] {
+			// 提升到上一层的节点没有兄弟节点
+			if let Some(sibling) = layer.get(index ^ 1) {
+				proof.push(*sibling);
+			}
+			index /= 2;
+		}
+		Some(proof)
+	}
+}
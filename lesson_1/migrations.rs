@@ -0,0 +1,184 @@
+//! Storage migrations for pallet-poe
+
+use crate::{
+	ClaimBody, ClaimCount, ClaimDigest, ClaimInfo, ClaimInfos, ClaimRecord, ClaimsByMoment, ClaimsByOwner, Config,
+	NamespaceId, Pallet, Proofs, Provenance, ProvenanceRecord, TransferKind, DEFAULT_NAMESPACE,
+};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+// v0版本的存储布局，只用于迁移和测试
+pub mod v0 {
+	use super::*;
+
+	// v0版本只有一个全局的存证集合，Key是存证内容，记录是(拥有者, 区块)元组
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, <T as Config>::MaxClaimLength>,
+		(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber),
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	// v1版本的存证记录，还没有时间
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimRecord<AccountId, BlockNumber> {
+		pub owner: AccountId,
+		pub block_number: BlockNumber,
+	}
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		ClaimBody<T>,
+		ClaimRecord<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>,
+	>;
+
+	// 一次迁移最多的v0存证数量，所有存证在同一个区块中迁移，超过时需要改成多区块迁移
+	pub const MAX_CLAIMS: u32 = 10_000;
+
+	// 迁移前检查v0的存证：总数不超过MAX_CLAIMS，每个账户的存证不超过MaxClaimsPerAccount
+	// 通过检查时返回存证数量，pre_upgrade和migrate都会调用
+	pub fn check<T: Config>() -> Result<u32, &'static str> {
+		let mut counts = BTreeMap::<T::AccountId, u32>::new();
+		let mut total = 0u32;
+		for (owner, _) in v0::Proofs::<T>::iter_values() {
+			total += 1;
+			ensure!(total <= MAX_CLAIMS, "too many v0 claims to migrate in one block");
+			let count = counts.entry(owner).or_default();
+			*count += 1;
+			ensure!(*count <= T::MaxClaimsPerAccount::get(), "a v0 account owns more than MaxClaimsPerAccount claims");
+		}
+		Ok(total)
+	}
+
+	// 把v0版本的存证移到默认命名空间，摘要算法记为Raw，(拥有者, 区块)元组转换成ClaimRecord结构体
+	// 同时加入拥有者的存证索引，补上没有元数据、创建时间为0的存证信息，以及在原区块创建的所有权记录
+	// 迁移过来的存证没有保留押金；没有通过check时不做任何修改，存储版本保持为0
+	pub fn migrate<T: Config>() -> Weight {
+		let checked = match check::<T>() {
+			Ok(checked) => checked as u64,
+			Err(e) => {
+				log::error!(target: "runtime::poe", "v0 claims were not migrated: {}", e);
+				return T::DbWeight::get().reads(MAX_CLAIMS as u64 + 1)
+			},
+		};
+
+		// 新旧两种Key在同一个存储前缀下，先取出所有旧的存证再写入，避免遍历到新写入的Key
+		// 存证数量已经检查过不超过MAX_CLAIMS
+		let old = v0::Proofs::<T>::drain().collect::<Vec<_>>();
+		for (claim, (owner, block_number)) in old {
+			let body = (ClaimDigest::Raw, claim);
+			let bounded_claim = (DEFAULT_NAMESPACE, body.clone());
+			ClaimsByOwner::<T>::insert(&owner, &bounded_claim, ());
+			ClaimCount::<T>::mutate(&owner, |count| *count = count.saturating_add(1));
+			let info = ClaimInfo::<T> {
+				description: Default::default(),
+				content_type: Default::default(),
+				uri: None,
+				created_at: 0,
+			};
+			ClaimInfos::<T>::insert(&bounded_claim, info);
+			ClaimsByMoment::<T>::insert(Pallet::<T>::moment_key(DEFAULT_NAMESPACE, 0), &body, ());
+			let created = ProvenanceRecord { owner: owner.clone(), acquired_at: block_number, kind: TransferKind::Created };
+			Provenance::<T>::insert(&bounded_claim, BoundedVec::try_from(sp_std::vec![created]).unwrap_or_default());
+			Proofs::<T>::insert(DEFAULT_NAMESPACE, body, ClaimRecord { owner, block_number });
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(checked.saturating_mul(3), checked.saturating_mul(7) + 1)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	// 为存证记录增加时间，迁移前的存证无法知道取得的时间，记为0
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated = 0u64;
+		Proofs::<T>::translate::<v1::ClaimRecord<T::AccountId, T::BlockNumber>, _>(|_, _, record| {
+			translated += 1;
+			Some(ClaimRecord { owner: record.owner, block_number: record.block_number, moment: 0 })
+		});
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated, translated + 1)
+	}
+}
+
+// 根据链上的存储版本依次执行还没有执行的迁移，已经是最新版本时什么都不做
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+		// v0的存证没有迁移，不能继续后面的迁移
+		if Pallet::<T>::on_chain_storage_version() < 1 {
+			return weight
+		}
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+	weight
+}
+
+// 迁移前记录存证的数量，只遍历Key，不需要解码记录；v0版本的Key是另一种布局，按v0的格式检查和计数
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<(), &'static str> {
+	let count = if Pallet::<T>::on_chain_storage_version() < 1 {
+		v1::check::<T>()?
+	} else {
+		Proofs::<T>::iter_keys().count() as u32
+	};
+	Pallet::<T>::set_temp_storage(count, "proof_count");
+	Ok(())
+}
+
+// 迁移后检查存储版本已经更新，所有存证都能按新的格式解码，并且索引保持一致：
+// 除了模块账户的公共存证，每个存证都在拥有者的存证索引中，每个存证都有存证信息，
+// 每个账户的ClaimCount等于索引中的存证数量，并且不超过MaxClaimsPerAccount
+#[cfg(feature = "try-runtime")]
+pub fn post_upgrade<T: Config>() -> Result<(), &'static str> {
+	ensure!(
+		Pallet::<T>::on_chain_storage_version() == crate::STORAGE_VERSION,
+		"pallet-poe storage version was not updated"
+	);
+	let count: u32 = Pallet::<T>::get_temp_storage("proof_count").ok_or("proof count was not recorded")?;
+	ensure!(Proofs::<T>::iter_values().count() as u32 == count, "some proofs were lost during the migration");
+
+	let public = Pallet::<T>::account_id();
+	let mut indexed = 0u32;
+	for (namespace, body, record) in Proofs::<T>::iter() {
+		let claim = (namespace, body);
+		ensure!(ClaimInfos::<T>::contains_key(&claim), "a proof has no claim info");
+		if record.owner != public {
+			ensure!(ClaimsByOwner::<T>::contains_key(&record.owner, &claim), "a proof is missing from the owner index");
+			indexed += 1;
+		}
+	}
+	ensure!(ClaimsByOwner::<T>::iter_keys().count() as u32 == indexed, "the owner index has stale entries");
+	for (owner, claim_count) in ClaimCount::<T>::iter() {
+		ensure!(claim_count <= T::MaxClaimsPerAccount::get(), "an account owns more than MaxClaimsPerAccount claims");
+		ensure!(
+			ClaimsByOwner::<T>::iter_key_prefix(&owner).count() as u32 == claim_count,
+			"ClaimCount does not match the owner index"
+		);
+	}
+	Ok(())
+}
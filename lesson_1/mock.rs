@@ -1,10 +1,14 @@
 
 use crate as pallet_poe;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64},
+	PalletId,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 };
 
@@ -19,6 +23,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
 		PoeModule: pallet_poe,
 	}
 );
@@ -41,7 +47,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -50,13 +56,107 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	// 测试中可以修改所有权记录满了之后的处理方式
+	pub static ProvenancePolicy: pallet_poe::ProvenancePruning = pallet_poe::ProvenancePruning::DropOldest;
+	pub const PoePalletId: PalletId = PalletId(*b"py/poe00");
+	// 测试中可以禁止重新创建已被撤销的存证
+	pub static ForbidReregistration: bool = false;
+	pub static MaxClaimsPerWindow: u32 = 100;
+	pub static MaxClaimsPerBlock: u32 = 100;
+}
+
 impl pallet_poe::Config for Test {
 	type MaxClaimLength = ConstU32<512>;
 	type Event = Event;
+	type Currency = Balances;
+	type ClaimDepositBase = ConstU64<10>;
+	type ClaimDepositPerByte = ConstU64<1>;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+	type MaxDescriptionLength = ConstU32<64>;
+	type MaxContentTypeLength = ConstU32<32>;
+	type MaxUriLength = ConstU32<128>;
+	type Time = Timestamp;
+	type MaxClaimsPerAccount = ConstU32<5>;
+	type MaxProvenanceLength = ConstU32<3>;
+	type ProvenancePruning = ProvenancePolicy;
+	type PalletId = PoePalletId;
+	// 工作量Hash至少8个前导0比特，测试中平均尝试256次
+	type UnsignedDifficulty = ConstU32<8>;
+	type UnsignedPriority = ConstU64<100>;
+	type UnsignedLongevity = ConstU64<64>;
+	type MinRevealDelay = ConstU64<2>;
+	type MaxRevealDelay = ConstU64<10>;
+	type MaxCommitmentsPerBlock = ConstU32<2>;
+	type CommitmentDeposit = ConstU64<5>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type ArbitrationOrigin = frame_system::EnsureRoot<u64>;
+	type DisputeBond = ConstU64<100>;
+	type Slashed = ();
+	type MaxAttestationLength = ConstU32<64>;
+	type MaxAttestersPerClaim = ConstU32<2>;
+	type MaxRevocationNoteLength = ConstU32<32>;
+	type HistoryDeposit = ConstU64<5>;
+	type ForbidReregistration = ForbidReregistration;
+	type NamespaceDeposit = ConstU64<50>;
+	type MaxNamespaceAdmins = ConstU32<2>;
+	type MaxClaimsPerWindow = MaxClaimsPerWindow;
+	type QuotaWindow = ConstU64<10>;
+	type MaxClaimsPerBlock = MaxClaimsPerBlock;
+	type Signature = TestSignature;
+	type Signer = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PoeBenchmarkHelper;
+	type WeightInfo = ();
+}
+
+// 测试签名：账户即为seed，签名内容就是消息本身
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PoeBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<u64, TestSignature> for PoeBenchmarkHelper {
+	fn account(seed: u32) -> u64 {
+		seed as u64
+	}
+
+	fn sign(seed: u32, message: &[u8]) -> TestSignature {
+		TestSignature(seed as u64, message.to_vec())
+	}
 }
 
 // 对区块的初始状态进行配置
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	new_test_ext_with_claims(vec![])
+}
+
+// 从创世存证开始的测试环境
+pub fn new_test_ext_with_claims(claims: Vec<(u64, Vec<u8>)>) -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	// 账户1、2、3有足够的余额支付存证押金，账户4余额为0
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	pallet_poe::GenesisConfig::<Test> { claims }.assimilate_storage(&mut t).unwrap();
+	t.into()
 }
@@ -0,0 +1,171 @@
+//! 存证模块的JSON-RPC接口
+//!
+//! 通过 [`pallet_poe::runtime_api::PoeApi`] 在指定区块（默认最佳块）的状态上查询存证，
+//! 存证内容以十六进制字节串传递。
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_poe::ClaimDigest;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_poe::runtime_api::PoeApi as PoeRuntimeApi;
+
+#[cfg(test)]
+mod tests;
+
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber> {
+	/// 查询存证的拥有者和存证所在区块
+	#[method(name = "poe_getClaim")]
+	fn get_claim(
+		&self,
+		digest: ClaimDigest,
+		claim: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber)>>;
+
+	/// 分页查询账户拥有的存证，cursor为上一页最后一个存证
+	#[method(name = "poe_claimsOf")]
+	fn claims_of(
+		&self,
+		owner: AccountId,
+		cursor: Option<(ClaimDigest, Bytes)>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ClaimDigest, Bytes)>>;
+
+	/// 存证是否存在
+	#[method(name = "poe_claimExists")]
+	fn claim_exists(&self, digest: ClaimDigest, claim: Bytes, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// 分页查询在[from, to)时间范围（Unix时间戳，秒）内创建的存证，返回(创建时间, 摘要算法, 存证内容)
+	/// cursor为上一页的最后一个结果；需要runtime实现PoeApi版本2
+	#[method(name = "poe_claimsCreatedBetween")]
+	fn claims_created_between(
+		&self,
+		from: u64,
+		to: u64,
+		cursor: Option<(u64, ClaimDigest, Bytes)>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, ClaimDigest, Bytes)>>;
+}
+
+/// 存证RPC的实现
+pub struct Poe<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Poe<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Runtime API调用失败
+const RUNTIME_ERROR: i32 = 1;
+/// 区块的runtime实现的PoeApi版本太低，不支持调用的方法
+const API_VERSION_ERROR: i32 = 2;
+
+fn runtime_error(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query proof of existence.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+fn api_version_error(required: u32) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		API_VERSION_ERROR,
+		"The runtime does not support this method.",
+		Some(format!("PoeApi version {} is required", required)),
+	))
+	.into()
+}
+
+impl<C, Block> Poe<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	// 未指定区块时使用最佳块
+	fn block_id(&self, at: Option<Block::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId, BlockNumber> PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+	for Poe<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn get_claim(
+		&self,
+		digest: ClaimDigest,
+		claim: Bytes,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber)>> {
+		let api = self.client.runtime_api();
+		api.get_claim(&self.block_id(at), digest, claim.to_vec()).map_err(runtime_error)
+	}
+
+	fn claims_of(
+		&self,
+		owner: AccountId,
+		cursor: Option<(ClaimDigest, Bytes)>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(ClaimDigest, Bytes)>> {
+		let api = self.client.runtime_api();
+		let cursor = cursor.map(|(digest, claim)| (digest, claim.to_vec()));
+		api.claims_of(&self.block_id(at), owner, cursor, limit)
+			.map(|claims| claims.into_iter().map(|(digest, claim)| (digest, claim.into())).collect())
+			.map_err(runtime_error)
+	}
+
+	fn claim_exists(&self, digest: ClaimDigest, claim: Bytes, at: Option<Block::Hash>) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		api.claim_exists(&self.block_id(at), digest, claim.to_vec()).map_err(runtime_error)
+	}
+
+	fn claims_created_between(
+		&self,
+		from: u64,
+		to: u64,
+		cursor: Option<(u64, ClaimDigest, Bytes)>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(u64, ClaimDigest, Bytes)>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		// 版本2才有这个方法，旧的runtime直接调用会失败
+		let supported = api
+			.has_api_with::<dyn PoeRuntimeApi<Block, AccountId, BlockNumber>, _>(&at, |version| version >= 2)
+			.map_err(runtime_error)?;
+		if !supported {
+			return Err(api_version_error(2))
+		}
+		let cursor = cursor.map(|(moment, digest, claim)| (moment, digest, claim.to_vec()));
+		api.claims_created_between(&at, from, to, cursor, limit)
+			.map(|claims| claims.into_iter().map(|(moment, digest, claim)| (moment, digest, claim.into())).collect())
+			.map_err(runtime_error)
+	}
+}
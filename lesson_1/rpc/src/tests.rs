@@ -0,0 +1,177 @@
+use super::*;
+use sp_api::ApiRef;
+use sp_blockchain::{BlockStatus, Info};
+use sp_core::H256;
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, NumberFor},
+	OpaqueExtrinsic,
+};
+
+type Header = generic::Header<u64, BlakeTwo256>;
+type Block = generic::Block<Header, OpaqueExtrinsic>;
+
+// 模拟的链上存证：(拥有者, 区块, 摘要算法, 存证内容)，创建时间用区块代替
+type MockClaims = Vec<(u64, u64, ClaimDigest, Vec<u8>)>;
+
+// 账户1在区块5和6分别创建了两个存证
+fn mock_claims() -> MockClaims {
+	vec![(1, 5, ClaimDigest::Raw, vec![0, 1]), (1, 6, ClaimDigest::Raw, vec![0, 2])]
+}
+
+// 模拟的客户端，不需要真正的runtime
+struct TestApi {
+	claims: MockClaims,
+}
+
+struct TestRuntimeApi {
+	claims: MockClaims,
+}
+
+impl ProvideRuntimeApi<Block> for TestApi {
+	type Api = TestRuntimeApi;
+
+	fn runtime_api<'a>(&'a self) -> ApiRef<'a, Self::Api> {
+		TestRuntimeApi { claims: self.claims.clone() }.into()
+	}
+}
+
+impl HeaderBackend<Block> for TestApi {
+	fn header(&self, _id: BlockId<Block>) -> sp_blockchain::Result<Option<Header>> {
+		Ok(None)
+	}
+
+	fn info(&self) -> Info<Block> {
+		Info {
+			best_hash: H256::zero(),
+			best_number: 0,
+			genesis_hash: H256::zero(),
+			finalized_hash: H256::zero(),
+			finalized_number: 0,
+			finalized_state: None,
+			number_leaves: 0,
+			block_gap: None,
+		}
+	}
+
+	fn status(&self, _id: BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
+		Ok(BlockStatus::Unknown)
+	}
+
+	fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+		Ok(None)
+	}
+
+	fn hash(&self, _number: NumberFor<Block>) -> sp_blockchain::Result<Option<H256>> {
+		Ok(None)
+	}
+}
+
+sp_api::mock_impl_runtime_apis! {
+	impl PoeRuntimeApi<Block, u64, u64> for TestRuntimeApi {
+		fn get_claim(&self, digest: ClaimDigest, claim: Vec<u8>) -> Option<(u64, u64)> {
+			self.claims
+				.iter()
+				.find(|(_, _, d, c)| *d == digest && *c == claim)
+				.map(|(owner, block, _, _)| (*owner, *block))
+		}
+
+		fn claims_of(
+			&self,
+			owner: u64,
+			cursor: Option<(ClaimDigest, Vec<u8>)>,
+			limit: u32,
+		) -> Vec<(ClaimDigest, Vec<u8>)> {
+			let owned = self.claims.iter().filter(|(o, _, _, _)| *o == owner).map(|(_, _, d, c)| (*d, c.clone()));
+			let skip = cursor
+				.and_then(|cursor| owned.clone().position(|claim| claim == cursor))
+				.map_or(0, |index| index + 1);
+			owned.skip(skip).take(limit as usize).collect()
+		}
+
+		fn claim_exists(&self, digest: ClaimDigest, claim: Vec<u8>) -> bool {
+			self.claims.iter().any(|(_, _, d, c)| *d == digest && *c == claim)
+		}
+
+		fn claims_created_between(
+			&self,
+			from: u64,
+			to: u64,
+			cursor: Option<(u64, ClaimDigest, Vec<u8>)>,
+			limit: u32,
+		) -> Vec<(u64, ClaimDigest, Vec<u8>)> {
+			let created = self
+				.claims
+				.iter()
+				.filter(|(_, block, _, _)| from <= *block && *block < to)
+				.map(|(_, block, d, c)| (*block, *d, c.clone()));
+			let skip = cursor
+				.and_then(|cursor| created.clone().position(|claim| claim == cursor))
+				.map_or(0, |index| index + 1);
+			created.skip(skip).take(limit as usize).collect()
+		}
+	}
+}
+
+fn new_rpc() -> Poe<TestApi, Block> {
+	Poe::new(Arc::new(TestApi { claims: mock_claims() }))
+}
+
+// 查询存证的拥有者和区块，不存在的存证返回None
+#[test]
+fn get_claim_works() {
+	let rpc = new_rpc();
+
+	assert_eq!(
+		PoeApiServer::<H256, u64, u64>::get_claim(&rpc, ClaimDigest::Raw, Bytes(vec![0, 1]), None).unwrap(),
+		Some((1, 5))
+	);
+	assert_eq!(
+		PoeApiServer::<H256, u64, u64>::get_claim(&rpc, ClaimDigest::Raw, Bytes(vec![0, 3]), None).unwrap(),
+		None
+	);
+}
+
+// 分页查询账户拥有的存证，存证内容以字节串返回
+#[test]
+fn claims_of_works() {
+	let rpc = new_rpc();
+
+	let first = PoeApiServer::<H256, u64, u64>::claims_of(&rpc, 1, None, 1, None).unwrap();
+	assert_eq!(first, vec![(ClaimDigest::Raw, Bytes(vec![0, 1]))]);
+
+	let cursor = first.last().cloned();
+	let second = PoeApiServer::<H256, u64, u64>::claims_of(&rpc, 1, cursor, 1, None).unwrap();
+	assert_eq!(second, vec![(ClaimDigest::Raw, Bytes(vec![0, 2]))]);
+
+	assert!(PoeApiServer::<H256, u64, u64>::claims_of(&rpc, 2, None, 10, None).unwrap().is_empty());
+}
+
+// 按创建时间分页查询存证
+#[test]
+fn claims_created_between_works() {
+	let rpc = new_rpc();
+
+	let first = PoeApiServer::<H256, u64, u64>::claims_created_between(&rpc, 0, 10, None, 1, None).unwrap();
+	assert_eq!(first, vec![(5, ClaimDigest::Raw, Bytes(vec![0, 1]))]);
+
+	let cursor = first.last().cloned();
+	let second = PoeApiServer::<H256, u64, u64>::claims_created_between(&rpc, 0, 10, cursor, 1, None).unwrap();
+	assert_eq!(second, vec![(6, ClaimDigest::Raw, Bytes(vec![0, 2]))]);
+
+	assert_eq!(
+		PoeApiServer::<H256, u64, u64>::claims_created_between(&rpc, 6, 10, None, 10, None).unwrap(),
+		vec![(6, ClaimDigest::Raw, Bytes(vec![0, 2]))]
+	);
+	assert!(PoeApiServer::<H256, u64, u64>::claims_created_between(&rpc, 0, 5, None, 10, None).unwrap().is_empty());
+}
+
+// 存证是否存在
+#[test]
+fn claim_exists_works() {
+	let rpc = new_rpc();
+
+	assert!(PoeApiServer::<H256, u64, u64>::claim_exists(&rpc, ClaimDigest::Raw, Bytes(vec![0, 2]), None).unwrap());
+	assert!(!PoeApiServer::<H256, u64, u64>::claim_exists(&rpc, ClaimDigest::Sha2_256, Bytes(vec![0, 2]), None)
+		.unwrap());
+}
@@ -0,0 +1,69 @@
+//! pallet_poe的Runtime API，供RPC和链下客户端查询存证，不需要自己计算存储Key
+//!
+//! 在runtime的`impl_runtime_apis!`中实现：
+//!
+//! ```ignore
+//! impl pallet_poe::runtime_api::PoeApi<Block, AccountId, BlockNumber> for Runtime {
+//! 	fn get_claim(digest: ClaimDigest, claim: Vec<u8>) -> Option<(AccountId, BlockNumber)> {
+//! 		PoeModule::get_claim(digest, claim)
+//! 	}
+//!
+//! 	fn claims_of(
+//! 		owner: AccountId,
+//! 		cursor: Option<(ClaimDigest, Vec<u8>)>,
+//! 		limit: u32,
+//! 	) -> Vec<(ClaimDigest, Vec<u8>)> {
+//! 		PoeModule::owned_claims(owner, cursor, limit)
+//! 	}
+//!
+//! 	fn claim_exists(digest: ClaimDigest, claim: Vec<u8>) -> bool {
+//! 		PoeModule::claim_exists(digest, claim)
+//! 	}
+//!
+//! 	fn claims_created_between(
+//! 		from: u64,
+//! 		to: u64,
+//! 		cursor: Option<(u64, ClaimDigest, Vec<u8>)>,
+//! 		limit: u32,
+//! 	) -> Vec<(u64, ClaimDigest, Vec<u8>)> {
+//! 		PoeModule::created_claims(from, to, cursor, limit)
+//! 	}
+//! }
+//! ```
+
+use crate::ClaimDigest;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// 版本2增加了claims_created_between，调用之前需要检查runtime实现的版本
+	#[api_version(2)]
+	pub trait PoeApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// 查询存证的拥有者和创建（或最近一次转移）的区块
+		fn get_claim(digest: ClaimDigest, claim: Vec<u8>) -> Option<(AccountId, BlockNumber)>;
+
+		/// 分页查询账户拥有的存证，cursor为上一页的最后一个存证，每页最多limit个
+		fn claims_of(
+			owner: AccountId,
+			cursor: Option<(ClaimDigest, Vec<u8>)>,
+			limit: u32,
+		) -> Vec<(ClaimDigest, Vec<u8>)>;
+
+		/// 存证是否存在
+		fn claim_exists(digest: ClaimDigest, claim: Vec<u8>) -> bool;
+
+		/// 分页查询在[from, to)时间范围（Unix时间戳，秒）内创建的存证，按创建时间排列，返回(创建时间, 摘要算法, 存证内容)
+		/// 从版本2开始提供
+		/// cursor为上一页的最后一个结果，每页最多limit个；从旧版本迁移过来的存证创建时间为0
+		fn claims_created_between(
+			from: u64,
+			to: u64,
+			cursor: Option<(u64, ClaimDigest, Vec<u8>)>,
+			limit: u32,
+		) -> Vec<(u64, ClaimDigest, Vec<u8>)>;
+	}
+}
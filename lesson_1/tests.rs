@@ -1,7 +1,21 @@
 use super::*;
-use crate::{mock::*, Error, Proofs};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
-
+use crate::{
+	migrations, mock::*, ClaimAction, ClaimDigest, ClaimMetadata, ClaimRecord, ClaimStatus, ClaimsByOwner,
+	DisputeResolution, Error, Expiries, OperatorScope, ProvenancePruning, ProvenanceRecord, Tombstone,
+	TransferKind, DEFAULT_NAMESPACE,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, GetStorageVersion, Hooks, StorageVersion},
+	unsigned::ValidateUnsigned,
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	DispatchError,
+};
 
 // 测试创建存证
 #[test]
@@ -9,12 +23,12 @@ fn create_claim_works() {
 	new_test_ext().execute_with(|| {
 		// 构造输入信息
 		let claim = vec![0, 1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
 
-		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
 		assert_eq!(
-			Proofs::<Test>::get(&bounded_claim),
-			Some((1, frame_system::Pallet::<Test>::block_number()))
+			PoeModule::proof_of(&bounded_claim),
+			Some(ClaimRecord { owner: 1, block_number: frame_system::Pallet::<Test>::block_number(), moment: 0 })
 		);
 	})
 }
@@ -25,10 +39,10 @@ fn create_claim_failed_when_claim_already() {
 	new_test_ext().execute_with(|| {
 		// 构造输入信息
 		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None);
 
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(1), claim.clone()),
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None),
 			Error::<Test>::ProofAlreadyExist
 		);
 	})
@@ -39,9 +53,9 @@ fn create_claim_failed_when_claim_already() {
 fn revoke_claim_works() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None);
 
-		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
 	})
 }
 
@@ -52,44 +66,53 @@ fn revoke_claim_failed_when_claim_not_exist() {
 		let claim = vec![0, 1];
 
 		assert_noop!(
-            PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+            PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]),
             Error::<Test>::ClaimNotExist
         );
 	})
 }
 
-
 // 撤销非交易发送方的存证
 #[test]
 fn revoke_claim_failed_when_is_not_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![0, 1];
 
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None);
 
 		assert_noop!(
-            PoeModule::revoke_claim(Origin::signed(2), claim.clone()),
+            PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 0, vec![]),
             Error::<Test>::NotClaimOwner
         );
 	})
 }
 
-
 // 测试转移存证成功
 #[test]
 fn transfer_claim_works() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![0, 1];
-		let _  = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let _  = PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None);
+
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
 
-		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, None));
+		// 接收方确认之前，存证仍属于原拥有者
+		assert_eq!(
+			PoeModule::proof_of(&bounded_claim),
+			Some(ClaimRecord { owner: 1, block_number: frame_system::Pallet::<Test>::block_number(), moment: 0 })
+		);
 
-		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
 
-		assert_eq!(Proofs::<Test>::get(&bounded_claim), Some((2, frame_system::Pallet::<Test>::block_number())));
+		assert_eq!(
+			PoeModule::proof_of(&bounded_claim),
+			Some(ClaimRecord { owner: 2, block_number: frame_system::Pallet::<Test>::block_number(), moment: 0 })
+		);
+		assert_eq!(PoeModule::pending_transfers(&bounded_claim), None);
 
 		assert_noop!(
-            PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+            PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]),
             Error::<Test>::NotClaimOwner
         );
 	})
@@ -100,26 +123,25 @@ fn transfer_claim_works() {
 fn transfer_claim_failed_when_claim_no_exist() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None);
 
 		let claim_temp = vec![2, 3];
 		assert_noop!(
-            PoeModule::transfer_claim(Origin::signed(1), claim_temp.clone(), 2),
+            PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim_temp.clone(), 2, None),
             Error::<Test>::ClaimNotExist
         );
 	})
 }
 
-
 // 测试转移存证，但转移的发起者非交易发送方
 #[test]
 fn transfer_claim_failed_not_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![0, 1];
-		let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None);
 
 		assert_noop!(
-            PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 3),
+            PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 3, None),
             Error::<Test>::NotClaimOwner
         );
 	})
@@ -132,8 +154,2077 @@ fn claim_too_long() {
 		let claim = vec![1; i32::MAX.try_into().unwrap()];
 
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(1), claim.clone()),
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None),
 			Error::<Test>::ClaimTooLong
 		);
 	})
-}
\ No newline at end of file
+}
+
+// 测试创建存证时按长度保留押金，撤销后退还
+#[test]
+fn claim_deposit_reserved_and_returned() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		// 押金 = 基础押金10 + 每字节1 * 2
+		assert_eq!(PoeModule::claim_deposit(claim.len()), 12);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(Balances::reserved_balance(1), 12);
+		assert_eq!(Balances::free_balance(1), 988);
+
+		// 撤销后押金中的5继续保留，用于支付墓碑和所有权记录
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(Balances::free_balance(1), 995);
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_eq!(PoeModule::history_deposits(&bounded_claim), Some((1, 5)));
+
+		// 重新创建并再次撤销时，之前保留的押金退还给原来的账户
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_eq!(PoeModule::history_deposits(&bounded_claim), Some((2, 5)));
+	})
+}
+
+// 余额不足，无法创建存证
+#[test]
+fn create_claim_failed_when_deposit_insufficient() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(4), ClaimDigest::Raw, claim.clone(), None, None),
+			Error::<Test>::InsufficientDeposit
+		);
+	})
+}
+
+// 转移存证时押金由新拥有者保留
+#[test]
+fn accept_transfer_moves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, None));
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 12);
+
+		// 新拥有者没有余额，转移失败
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 4, None));
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(4), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::InsufficientDeposit
+		);
+	})
+}
+
+// 测试存证到期后在区块开始时被清理
+#[test]
+fn claim_expires_on_initialize() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), Some(3), None));
+
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_eq!(PoeModule::claim_expiry(&bounded_claim), Some(3));
+		assert_eq!(PoeModule::expiries(3).into_inner(), vec![bounded_claim.clone()]);
+
+		// 到期前存证仍然有效
+		PoeModule::on_initialize(2);
+		assert!(PoeModule::proof_of(&bounded_claim).is_some());
+
+		PoeModule::on_initialize(3);
+		assert!(PoeModule::proof_of(&bounded_claim).is_none());
+		assert!(!Expiries::<Test>::contains_key(3));
+		assert_eq!(PoeModule::claim_expiry(&bounded_claim), None);
+		// 押金已退还，只保留所有权记录的押金
+		assert_eq!(Balances::reserved_balance(1), 5);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimExpired(1, bounded_claim)));
+	})
+}
+
+// 到期区块不能早于当前区块，且每个区块到期的存证数量有上限
+#[test]
+fn create_claim_failed_with_invalid_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], Some(5), None),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], Some(6), None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], Some(6), None));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 3], Some(6), None),
+			Error::<Test>::TooManyExpiries
+		);
+	})
+}
+
+// 撤销存证时同时从到期索引中删除
+#[test]
+fn revoke_claim_removes_expiry() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), Some(3), None));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+
+		assert!(!Expiries::<Test>::contains_key(3));
+		assert_eq!(
+			PoeModule::on_initialize(3),
+			<() as crate::WeightInfo>::on_initialize(0, 0, <Test as Config>::MaxAttestersPerClaim::get())
+		);
+	})
+}
+
+// 创建存证时记录元数据和创建时间
+#[test]
+fn create_claim_with_metadata_works() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(12_000);
+		let claim = vec![0, 1];
+		let metadata = ClaimMetadata {
+			description: b"contract".to_vec(),
+			content_type: b"application/pdf".to_vec(),
+			uri: Some(b"ipfs://claim".to_vec()),
+		};
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, Some(metadata)));
+
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let info = PoeModule::claim_info(&bounded_claim).unwrap();
+		assert_eq!(info.description.into_inner(), b"contract".to_vec());
+		assert_eq!(info.content_type.into_inner(), b"application/pdf".to_vec());
+		assert_eq!(info.uri.map(|uri| uri.into_inner()), Some(b"ipfs://claim".to_vec()));
+		assert_eq!(info.created_at, 12);
+
+		// 撤销存证时删除元数据
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert_eq!(PoeModule::claim_info(&bounded_claim), None);
+	})
+}
+
+// 拥有者修改元数据，创建时间不变
+#[test]
+fn set_claim_metadata_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(12_000);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		let metadata = ClaimMetadata { description: b"amended".to_vec(), ..Default::default() };
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(2), ClaimDigest::Raw, claim.clone(), metadata.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(1), ClaimDigest::Raw, vec![2, 3], metadata.clone()),
+			Error::<Test>::ClaimNotExist
+		);
+
+		assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), ClaimDigest::Raw, claim.clone(), metadata));
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let info = PoeModule::claim_info(&bounded_claim).unwrap();
+		assert_eq!(info.description.into_inner(), b"amended".to_vec());
+		assert_eq!(info.created_at, 12);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimMetadataSet(1, bounded_claim)));
+	})
+}
+
+// 元数据超过最大长度
+#[test]
+fn claim_metadata_too_long() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let too_long = |description: usize, content_type: usize, uri: usize| ClaimMetadata {
+			description: vec![1; description],
+			content_type: vec![1; content_type],
+			uri: Some(vec![1; uri]),
+		};
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, Some(too_long(65, 1, 1))),
+			Error::<Test>::DescriptionTooLong
+		);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, Some(too_long(1, 33, 1))),
+			Error::<Test>::ContentTypeTooLong
+		);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, Some(too_long(1, 1, 129))),
+			Error::<Test>::UriTooLong
+		);
+	})
+}
+
+// 存证内容的长度必须符合摘要算法
+#[test]
+fn create_claim_failed_with_invalid_digest_length() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Blake2_256, vec![0; 31], None, None),
+			Error::<Test>::InvalidDigestLength
+		);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Sha3_512, vec![0; 32], None, None),
+			Error::<Test>::InvalidDigestLength
+		);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Sha2_256, vec![0; 32], None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Sha3_512, vec![0; 64], None, None));
+	})
+}
+
+// 相同的内容使用不同的摘要算法是不同的存证
+#[test]
+fn same_bytes_with_different_digests_are_distinct_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![7; 32];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Blake2_256, claim.clone(), None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Keccak256, claim.clone(), None, None));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(2), ClaimDigest::Blake2_256, claim.clone(), None, None),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		let blake2 = PoeModule::bounded_claim(ClaimDigest::Blake2_256, claim.clone()).unwrap();
+		let keccak = PoeModule::bounded_claim(ClaimDigest::Keccak256, claim.clone()).unwrap();
+		assert_eq!(PoeModule::proof_of(&blake2).map(|record| record.owner), Some(1));
+		assert_eq!(PoeModule::proof_of(&keccak).map(|record| record.owner), Some(2));
+
+		// 撤销时需要指定摘要算法
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Sha2_256, claim.clone(), 0, vec![]),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Blake2_256, claim.clone(), 0, vec![]));
+		assert!(PoeModule::proof_of(&keccak).is_some());
+	})
+}
+
+// 转移需要接收方确认，并触发相应事件
+#[test]
+fn two_step_transfer_emits_events() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, Some(5)));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::TransferProposed(1, bounded_claim.clone(), 2, Some(5))));
+
+		// 只有接收方可以确认
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(3), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::NotTransferRecipient
+		);
+
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimTransfered(1, bounded_claim, 2)));
+
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::NoPendingTransfer
+		);
+	})
+}
+
+// 转移请求到期后不能再确认
+#[test]
+fn accept_transfer_failed_when_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		assert_noop!(
+			PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, Some(3)));
+
+		System::set_block_number(3);
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::TransferExpired
+		);
+	})
+}
+
+// 拥有者和接收方都可以取消转移，其他账户不可以
+#[test]
+fn cancel_transfer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		assert_noop!(
+			PoeModule::cancel_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::NoPendingTransfer
+		);
+
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, None));
+		assert_noop!(
+			PoeModule::cancel_transfer(Origin::signed(3), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::cancel_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone()));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::TransferCancelled(1, bounded_claim.clone(), 2)));
+
+		// 接收方拒绝
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, None));
+		assert_ok!(PoeModule::cancel_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+		assert_eq!(PoeModule::pending_transfers(&bounded_claim), None);
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+	})
+}
+
+// 账户的存证索引在创建、转移、撤销时保持一致
+#[test]
+fn claims_by_owner_index_is_consistent() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert!(ClaimsByOwner::<Test>::contains_key(1, &bounded_claim));
+		assert_eq!(PoeModule::claim_count(1), 1);
+
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, None));
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+		assert!(!ClaimsByOwner::<Test>::contains_key(1, &bounded_claim));
+		assert!(ClaimsByOwner::<Test>::contains_key(2, &bounded_claim));
+		assert_eq!(PoeModule::claim_count(1), 0);
+		assert_eq!(PoeModule::claim_count(2), 1);
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert!(!ClaimsByOwner::<Test>::contains_key(2, &bounded_claim));
+		assert_eq!(PoeModule::claim_count(2), 0);
+	})
+}
+
+// 每个账户拥有的存证数量有上限，接收转移时同样检查
+#[test]
+fn too_many_claims_per_account() {
+	new_test_ext().execute_with(|| {
+		for i in 0..5u8 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![i], None, None));
+		}
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![5], None, None),
+			Error::<Test>::TooManyClaims
+		);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, vec![5], None, None));
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, vec![5], 1, None));
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(1), ClaimDigest::Raw, vec![5]),
+			Error::<Test>::TooManyClaims
+		);
+	})
+}
+
+// 分页查询账户拥有的存证
+#[test]
+fn claims_of_paginates() {
+	new_test_ext().execute_with(|| {
+		for i in 0..5u8 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![i], None, None));
+		}
+
+		let first = PoeModule::claims_of(&1, None, 2);
+		assert_eq!(first.len(), 2);
+		let second = PoeModule::claims_of(&1, first.last().cloned(), 2);
+		assert_eq!(second.len(), 2);
+		let third = PoeModule::claims_of(&1, second.last().cloned(), 2);
+		assert_eq!(third.len(), 1);
+
+		// 各页之间没有重复，合起来是全部存证
+		let all: Vec<_> = first.into_iter().chain(second).chain(third).collect();
+		for i in 0..5u8 {
+			assert!(all.contains(&PoeModule::bounded_claim(ClaimDigest::Raw, vec![i]).unwrap()));
+		}
+
+		assert!(PoeModule::claims_of(&2, None, 10).is_empty());
+	})
+}
+
+// Runtime API使用的查询函数，参数和返回值都是不带长度限制的类型
+#[test]
+fn runtime_api_queries_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], None, None));
+
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, vec![0, 1]), Some((1, 5)));
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, vec![0, 3]), None);
+		assert!(PoeModule::claim_exists(ClaimDigest::Raw, vec![0, 2]));
+		assert!(!PoeModule::claim_exists(ClaimDigest::Sha2_256, vec![0, 2]));
+
+		// 超过最大长度的存证不会存在
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, vec![0; 513]), None);
+		assert!(!PoeModule::claim_exists(ClaimDigest::Raw, vec![0; 513]));
+
+		let first = PoeModule::owned_claims(1, None, 1);
+		assert_eq!(first.len(), 1);
+		let second = PoeModule::owned_claims(1, first.last().cloned(), 1);
+		assert_eq!(second.len(), 1);
+		assert_ne!(first, second);
+		assert!(PoeModule::owned_claims(1, second.last().cloned(), 1).is_empty());
+	})
+}
+
+// 其他命名空间的存证穿插在中间时，每一页仍然是完整的，并且可以用上一页的最后一个存证继续翻页
+#[test]
+fn owned_claims_skips_other_namespaces() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		// MaxClaimsPerAccount为5，每个命名空间各创建两个
+		for i in 0 .. 2u8 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, i], None, None));
+			assert_ok!(PoeModule::create_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, vec![1, i], None, None));
+		}
+
+		let mut pages = Vec::new();
+		let mut cursor = None;
+		loop {
+			let page = PoeModule::owned_claims(1, cursor, 1);
+			if page.is_empty() {
+				break
+			}
+			assert_eq!(page.len(), 1);
+			cursor = page.last().cloned();
+			pages.extend(page);
+		}
+
+		pages.sort_by_key(|(_, claim)| claim.clone());
+		assert_eq!(pages, (0 .. 2u8).map(|i| (ClaimDigest::Raw, vec![0, i])).collect::<Vec<_>>());
+		assert_eq!(PoeModule::owned_claims(1, None, 10).len(), 2);
+	})
+}
+
+// 转移存证
+fn transfer(from: u64, to: u64, claim: &[u8]) {
+	assert_ok!(PoeModule::propose_transfer(Origin::signed(from), ClaimDigest::Raw, claim.to_vec(), to, None));
+	assert_ok!(PoeModule::accept_transfer(Origin::signed(to), ClaimDigest::Raw, claim.to_vec()));
+}
+
+// 所有权记录按时间顺序追加
+#[test]
+fn provenance_records_chain_of_custody() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		System::set_block_number(2);
+		transfer(1, 2, &claim);
+
+		assert_eq!(
+			PoeModule::provenance_of(&bounded_claim),
+			vec![
+				ProvenanceRecord { owner: 1, acquired_at: 1, kind: TransferKind::Created },
+				ProvenanceRecord { owner: 2, acquired_at: 2, kind: TransferKind::Transferred },
+			]
+		);
+
+		// 撤销存证时保留所有权记录，重新创建时继续追加
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert_eq!(PoeModule::provenance_of(&bounded_claim).len(), 2);
+		System::set_block_number(3);
+		assert_ok!(PoeModule::create_claim(Origin::signed(3), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(
+			PoeModule::provenance_of(&bounded_claim),
+			vec![
+				ProvenanceRecord { owner: 1, acquired_at: 1, kind: TransferKind::Created },
+				ProvenanceRecord { owner: 2, acquired_at: 2, kind: TransferKind::Transferred },
+				ProvenanceRecord { owner: 3, acquired_at: 3, kind: TransferKind::Created },
+			]
+		);
+
+		// 记录满了之后删除最早的记录，保留当前存证的创建记录
+		System::set_block_number(4);
+		transfer(3, 1, &claim);
+		assert_eq!(
+			PoeModule::provenance_of(&bounded_claim),
+			vec![
+				ProvenanceRecord { owner: 2, acquired_at: 2, kind: TransferKind::Transferred },
+				ProvenanceRecord { owner: 3, acquired_at: 3, kind: TransferKind::Created },
+				ProvenanceRecord { owner: 1, acquired_at: 4, kind: TransferKind::Transferred },
+			]
+		);
+	})
+}
+
+// 存证到期后保留所有权记录
+#[test]
+fn provenance_survives_expiry() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), Some(3), None));
+		PoeModule::on_initialize(3);
+
+		assert_eq!(PoeModule::proof_of(&bounded_claim), None);
+		assert_eq!(
+			PoeModule::provenance_of(&bounded_claim),
+			vec![ProvenanceRecord { owner: 1, acquired_at: 1, kind: TransferKind::Created }]
+		);
+	})
+}
+
+// 记录满了之后删除最早的转移记录，保留创建记录
+#[test]
+fn provenance_drops_oldest_transfer_when_full() {
+	new_test_ext().execute_with(|| {
+		ProvenancePolicy::set(ProvenancePruning::DropOldest);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		transfer(1, 2, &claim);
+		transfer(2, 3, &claim);
+		transfer(3, 1, &claim);
+
+		let owners: Vec<_> = PoeModule::provenance_of(&bounded_claim).into_iter().map(|r| (r.owner, r.kind)).collect();
+		assert_eq!(
+			owners,
+			vec![(1, TransferKind::Created), (3, TransferKind::Transferred), (1, TransferKind::Transferred)]
+		);
+	})
+}
+
+// 记录满了之后拒绝转移
+#[test]
+fn provenance_rejects_transfer_when_full() {
+	new_test_ext().execute_with(|| {
+		ProvenancePolicy::set(ProvenancePruning::RejectWhenFull);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		transfer(1, 2, &claim);
+		transfer(2, 3, &claim);
+
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(3), ClaimDigest::Raw, claim.clone(), 1, None));
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::ProvenanceFull
+		);
+		ProvenancePolicy::set(ProvenancePruning::DropOldest);
+	})
+}
+
+// Merkle树生成的证明可以通过校验
+#[test]
+fn merkle_tree_proofs_verify() {
+	use crate::merkle::{verify_proof, MerkleTree};
+
+	assert_eq!(MerkleTree::new(&[]).root(), None);
+
+	for count in 1..=7u8 {
+		let leaves: Vec<H256> = (0..count).map(H256::repeat_byte).collect();
+		let tree = MerkleTree::new(&leaves);
+		let root = tree.root().unwrap();
+
+		for (index, leaf) in leaves.iter().enumerate() {
+			let proof = tree.proof(index).unwrap();
+			assert!(verify_proof(&root, leaf, &proof));
+			// 证明不能用于其他文档
+			assert!(!verify_proof(&root, &H256::repeat_byte(0xff), &proof));
+		}
+		assert_eq!(tree.proof(count as usize), None);
+	}
+}
+
+// 批量存证中的文档以root所在区块作为存证时间
+#[test]
+fn batch_root_verify_inclusion_works() {
+	use crate::merkle::MerkleTree;
+
+	new_test_ext().execute_with(|| {
+		let leaves: Vec<H256> = (0..5u8).map(H256::repeat_byte).collect();
+		let tree = MerkleTree::new(&leaves);
+		let root = tree.root().unwrap();
+
+		assert_noop!(PoeModule::create_batch_root(Origin::signed(1), root, 0), Error::<Test>::EmptyBatch);
+
+		System::set_block_number(3);
+		assert_ok!(PoeModule::create_batch_root(Origin::signed(1), root, 5));
+		assert_noop!(
+			PoeModule::create_batch_root(Origin::signed(2), root, 5),
+			Error::<Test>::BatchRootAlreadyExist
+		);
+		// 按32字节的存证保留押金
+		assert_eq!(Balances::reserved_balance(1), 42);
+
+		System::set_block_number(10);
+		let proof = tree.proof(2).unwrap();
+		assert_eq!(PoeModule::verify_inclusion(&root, &leaves[2], &proof), Some(3));
+		assert_eq!(PoeModule::verify_inclusion(&root, &leaves[3], &proof), None);
+		assert_eq!(PoeModule::verify_inclusion(&H256::zero(), &leaves[2], &proof), None);
+
+		assert_noop!(PoeModule::revoke_batch_root(Origin::signed(2), root), Error::<Test>::NotClaimOwner);
+		assert_ok!(PoeModule::revoke_batch_root(Origin::signed(1), root));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(PoeModule::verify_inclusion(&root, &leaves[2], &proof), None);
+	})
+}
+
+// 操作员代替拥有者创建和撤销存证，事件同时记录操作员和拥有者
+#[test]
+fn operator_acts_on_behalf_of_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(Origin::signed(2), 1, ClaimDigest::Raw, claim.clone(), None, None),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::approve_operator(Origin::signed(1), 1, None, None),
+			Error::<Test>::InvalidOperator
+		);
+
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 2, None, None));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::OperatorApproved(1, 2, OperatorScope::all(), None)));
+
+		// 存证属于拥有者，押金也从拥有者的账户保留
+		assert_ok!(PoeModule::create_claim_on_behalf(Origin::signed(2), 1, ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+		assert_eq!(Balances::reserved_balance(1), 12);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_has_event(mock::Event::PoeModule(crate::Event::ClaimCreated(1, bounded_claim.clone(), 0)));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::OperatorActed(2, 1, bounded_claim.clone(), ClaimAction::Create)));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		System::assert_has_event(mock::Event::PoeModule(crate::Event::ClaimRevoked(1, bounded_claim.clone())));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::OperatorActed(2, 1, bounded_claim, ClaimAction::Revoke)));
+		assert_eq!(Balances::reserved_balance(1), 5);
+	})
+}
+
+// 操作员只能执行授权范围内的操作，授权到期或取消后不能再操作
+#[test]
+fn operator_scope_and_expiry_are_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		let scope = OperatorScope { create: true, revoke: false, transfer: true, set_metadata: false };
+		assert_noop!(
+			PoeModule::approve_operator(Origin::signed(1), 2, Some(scope), Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 2, Some(scope), Some(3)));
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 0, vec![]),
+			Error::<Test>::OperatorNotPermitted
+		);
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(2), ClaimDigest::Raw, claim.clone(), Default::default()),
+			Error::<Test>::OperatorNotPermitted
+		);
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 3, None));
+		assert_ok!(PoeModule::cancel_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+
+		System::set_block_number(3);
+		assert_noop!(
+			PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 3, None),
+			Error::<Test>::OperatorExpired
+		);
+
+		assert_ok!(PoeModule::revoke_operator(Origin::signed(1), 2));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::OperatorRevoked(1, 2)));
+		assert_noop!(PoeModule::revoke_operator(Origin::signed(1), 2), Error::<Test>::OperatorNotExist);
+		assert_noop!(
+			PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 3, None),
+			Error::<Test>::NotClaimOwner
+		);
+	})
+}
+
+// 接收方的操作员可以代替接收方确认转移
+#[test]
+fn operator_accepts_transfer_for_recipient() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 2, None));
+		assert_ok!(PoeModule::approve_operator(Origin::signed(2), 3, None, None));
+
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(3), ClaimDigest::Raw, claim.clone()));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(2));
+		assert_eq!(Balances::reserved_balance(2), 12);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::OperatorActed(3, 2, bounded_claim, ClaimAction::Transfer)));
+	})
+}
+
+// relayer代替受益人创建存证：relayer保留押金，存证属于签名的受益人
+#[test]
+fn create_claim_for_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let payload = PoeModule::create_claim_payload(&4, ClaimDigest::Raw, &claim, 0);
+
+		assert_ok!(PoeModule::create_claim_for(
+			Origin::signed(1),
+			4,
+			ClaimDigest::Raw,
+			claim.clone(),
+			0,
+			TestSignature(4, payload.clone())
+		));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(4));
+		assert_eq!(PoeModule::nonces(4), 1);
+		assert_eq!(Balances::reserved_balance(1), 12);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimCreated(4, bounded_claim.clone(), 0)));
+
+		// 同一个签名不能重放
+		assert_noop!(
+			PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, claim.clone(), 0, TestSignature(4, payload)),
+			Error::<Test>::InvalidNonce
+		);
+
+		// 撤销时退还relayer的押金，为墓碑保留的部分仍由relayer保留
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(4), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert_eq!(Balances::reserved_balance(1), 5);
+	})
+}
+
+// 签名必须由受益人对完整的内容签名
+#[test]
+fn create_claim_for_rejects_invalid_signature() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let payload = PoeModule::create_claim_payload(&4, ClaimDigest::Raw, &claim, 0);
+
+		// 签名者不是受益人
+		assert_noop!(
+			PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, claim.clone(), 0, TestSignature(3, payload.clone())),
+			Error::<Test>::InvalidSignature
+		);
+		// 签名的存证内容与提交的不一致
+		assert_noop!(
+			PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, vec![2, 3], 0, TestSignature(4, payload)),
+			Error::<Test>::InvalidSignature
+		);
+		assert_noop!(
+			PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, claim.clone(), 1, TestSignature(4, vec![])),
+			Error::<Test>::InvalidNonce
+		);
+	})
+}
+
+// 在其他链上（创世区块Hash不同）签名的内容不能在本链重放
+#[test]
+fn create_claim_for_rejects_other_chain_signature() {
+	use codec::Encode;
+
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let genesis_hash = System::block_hash(0);
+		assert_ne!(genesis_hash, H256::zero());
+
+		let payload = (b"poe/create_claim_for", H256::zero(), 4u64, ClaimDigest::Raw, &claim[..], 0u64).encode();
+		assert_noop!(
+			PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, claim.clone(), 0, TestSignature(4, payload)),
+			Error::<Test>::InvalidSignature
+		);
+
+		let payload = (b"poe/create_claim_for", genesis_hash, 4u64, ClaimDigest::Raw, &claim[..], 0u64).encode();
+		assert_eq!(payload, PoeModule::create_claim_payload(&4, ClaimDigest::Raw, &claim, 0));
+		assert_ok!(PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, claim, 0, TestSignature(4, payload)));
+	})
+}
+
+// 转移通过create_claim_for创建的存证后，押金由新拥有者保留，并退还relayer
+#[test]
+fn transfer_moves_relayer_deposit_to_new_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let payload = PoeModule::create_claim_payload(&4, ClaimDigest::Raw, &claim, 0);
+		assert_ok!(PoeModule::create_claim_for(Origin::signed(1), 4, ClaimDigest::Raw, claim.clone(), 0, TestSignature(4, payload)));
+
+		transfer(4, 2, &claim);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 12);
+	})
+}
+
+// 找到满足难度要求的nonce
+fn mine(digest: ClaimDigest, claim: &[u8]) -> u64 {
+	(0..).find(|nonce| PoeModule::meets_difficulty(&PoeModule::unsigned_work(digest, claim, *nonce))).unwrap()
+}
+
+// 带有工作量证明的无签名存证属于模块账户，不保留押金
+#[test]
+fn create_claim_unsigned_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let nonce = mine(ClaimDigest::Raw, &claim);
+
+		assert_noop!(
+			PoeModule::create_claim_unsigned(Origin::signed(1), ClaimDigest::Raw, claim.clone(), nonce),
+			DispatchError::BadOrigin
+		);
+		let bad_nonce = (0..).find(|n| !PoeModule::meets_difficulty(&PoeModule::unsigned_work(ClaimDigest::Raw, &claim, *n))).unwrap();
+		assert_noop!(
+			PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, claim.clone(), bad_nonce),
+			Error::<Test>::InsufficientWork
+		);
+
+		assert_ok!(PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, claim.clone(), nonce));
+		let pallet_account = PoeModule::account_id();
+		assert_eq!(
+			PoeModule::proof_of(&bounded_claim),
+			Some(ClaimRecord { owner: pallet_account, block_number: 1, moment: 0 })
+		);
+		assert_eq!(PoeModule::claim_deposits(&bounded_claim), None);
+		assert_eq!(PoeModule::claim_count(pallet_account), 0);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimCreated(pallet_account, bounded_claim, 0)));
+
+		assert_noop!(
+			PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, claim.clone(), nonce),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+// 公共存证没有押金，任何途径都不能把它转给别的账户，也不能发起争议；ForceOrigin可以撤销
+#[test]
+fn public_claims_cannot_be_transferred() {
+	use frame_support::traits::tokens::nonfungibles::{Inspect, Transfer};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let salt = H256::repeat_byte(1);
+		assert_ok!(PoeModule::commit_claim(Origin::signed(1), PoeModule::commitment_of(ClaimDigest::Raw, &claim, &salt)));
+
+		System::set_block_number(2);
+		let nonce = mine(ClaimDigest::Raw, &claim);
+		assert_ok!(PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, claim.clone(), nonce));
+		let pallet_account = PoeModule::account_id();
+
+		assert_noop!(
+			PoeModule::force_transfer_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 2, 0),
+			Error::<Test>::PublicClaim
+		);
+		assert_noop!(
+			PoeModule::dispute_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::PublicClaim
+		);
+		assert!(!<PoeModule as Inspect<u64>>::can_transfer(&(), &bounded_claim.1));
+		assert_noop!(<PoeModule as Transfer<u64>>::transfer(&(), &bounded_claim.1, &2), Error::<Test>::PublicClaim);
+
+		// 更早的承诺者揭示时不能取得，承诺押金退还
+		System::set_block_number(4);
+		assert_ok!(PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), salt));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(pallet_account));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(PoeModule::claim_deposits(&bounded_claim), None);
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert!(PoeModule::proof_of(&bounded_claim).is_none());
+	})
+}
+
+// 交易池校验：以存证作为provides标签，工作量不足或存证已存在的交易被拒绝
+#[test]
+fn validate_unsigned_dedupes_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let nonce = mine(ClaimDigest::Raw, &claim);
+		let call = crate::Call::<Test>::create_claim_unsigned { digest: ClaimDigest::Raw, claim: claim.clone(), nonce };
+
+		let valid = PoeModule::validate_unsigned(TransactionSource::External, &call).unwrap();
+		assert_eq!(valid.priority, 100);
+		assert_eq!(valid.longevity, 64);
+		// 同一存证不同nonce的交易提供相同的标签
+		let other_nonce = (nonce + 1..).find(|n| PoeModule::meets_difficulty(&PoeModule::unsigned_work(ClaimDigest::Raw, &claim, *n))).unwrap();
+		let other = crate::Call::<Test>::create_claim_unsigned { digest: ClaimDigest::Raw, claim: claim.clone(), nonce: other_nonce };
+		assert_eq!(PoeModule::validate_unsigned(TransactionSource::External, &other).unwrap().provides, valid.provides);
+
+		let bad_nonce = (0..).find(|n| !PoeModule::meets_difficulty(&PoeModule::unsigned_work(ClaimDigest::Raw, &claim, *n))).unwrap();
+		let bad = crate::Call::<Test>::create_claim_unsigned { digest: ClaimDigest::Raw, claim: claim.clone(), nonce: bad_nonce };
+		assert_eq!(PoeModule::validate_unsigned(TransactionSource::External, &bad), InvalidTransaction::BadProof.into());
+
+		let signed_call = crate::Call::<Test>::revoke_claim { digest: ClaimDigest::Raw, claim: claim.clone(), reason: 0, note: vec![] };
+		assert_eq!(PoeModule::validate_unsigned(TransactionSource::External, &signed_call), InvalidTransaction::Call.into());
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(PoeModule::validate_unsigned(TransactionSource::External, &call), InvalidTransaction::Stale.into());
+	})
+}
+
+// 提交承诺之后在揭示窗口内揭示，成为存证的拥有者
+#[test]
+fn commit_reveal_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let salt = H256::repeat_byte(7);
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let commitment = PoeModule::commitment_of(ClaimDigest::Raw, &claim, &salt);
+
+		assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimCommitted(1, commitment)));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		// 同一个承诺只能提交一次，复制别人的承诺没有用
+		assert_noop!(PoeModule::commit_claim(Origin::signed(2), commitment), Error::<Test>::CommitmentAlreadyExist);
+		// 余额不足，无法保留承诺押金
+		assert_noop!(PoeModule::commit_claim(Origin::signed(4), H256::repeat_byte(1)), Error::<Test>::InsufficientDeposit);
+
+		assert_noop!(
+			PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), salt),
+			Error::<Test>::RevealTooEarly
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			PoeModule::reveal_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), salt),
+			Error::<Test>::NotCommitter
+		);
+		assert_noop!(
+			PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), H256::repeat_byte(8)),
+			Error::<Test>::CommitmentNotExist
+		);
+		assert_ok!(PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), salt));
+		assert_eq!(PoeModule::proof_of(&bounded_claim), Some(ClaimRecord { owner: 1, block_number: 3, moment: 0 }));
+		assert_eq!(PoeModule::claim_committed_at(&bounded_claim), Some(1));
+		assert_eq!(PoeModule::commitments(commitment), None);
+		// 承诺押金已退还，只保留存证押金
+		assert_eq!(Balances::reserved_balance(1), 12);
+		assert!(!crate::CommitmentExpiries::<Test>::contains_key(12));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimRevealed(1, bounded_claim)));
+	})
+}
+
+// 抢先创建存证的账户会被更早的承诺者取代
+#[test]
+fn earliest_committer_wins() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		let early = PoeModule::commitment_of(ClaimDigest::Raw, &claim, &H256::repeat_byte(1));
+		let late = PoeModule::commitment_of(ClaimDigest::Raw, &claim, &H256::repeat_byte(2));
+		assert_ok!(PoeModule::commit_claim(Origin::signed(1), early));
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::commit_claim(Origin::signed(3), late));
+
+		// 账户2在交易池中看到揭示交易后抢先创建存证
+		System::set_block_number(4);
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), H256::repeat_byte(1)));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(1), 12);
+
+		// 更晚的承诺不能取代更早的承诺
+		assert_noop!(
+			PoeModule::reveal_claim(Origin::signed(3), ClaimDigest::Raw, claim.clone(), H256::repeat_byte(2)),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+// 争议中的存证不能被更早的承诺者通过揭示取得
+#[test]
+fn reveal_cannot_take_disputed_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let salt = H256::repeat_byte(1);
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::commit_claim(Origin::signed(1), PoeModule::commitment_of(ClaimDigest::Raw, &claim, &salt)));
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::dispute_claim(Origin::signed(3), ClaimDigest::Raw, claim.clone()));
+
+		System::set_block_number(4);
+		assert_noop!(
+			PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), salt),
+			Error::<Test>::ClaimDisputed
+		);
+
+		// 争议结束后可以揭示
+		assert_ok!(PoeModule::resolve_dispute(Origin::root(), ClaimDigest::Raw, claim.clone(), DisputeResolution::Rejected));
+		assert_ok!(PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim, salt));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+	})
+}
+
+// ForceOrigin、仲裁和无签名交易确定拥有者的存证不能被更早的承诺者通过揭示取得，承诺押金照常退还
+#[test]
+fn reveal_skips_privileged_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let salt = H256::repeat_byte(1);
+		let forced = vec![0, 1];
+		let transferred = vec![0, 2];
+		for claim in [&forced, &transferred] {
+			let commitment = PoeModule::commitment_of(ClaimDigest::Raw, claim, &salt);
+			assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+		}
+
+		System::set_block_number(2);
+		let later_salt = H256::repeat_byte(2);
+		let commitment = PoeModule::commitment_of(ClaimDigest::Raw, &forced, &later_salt);
+		assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+
+		System::set_block_number(3);
+		assert_ok!(PoeModule::force_create_claim(Origin::root(), 2, ClaimDigest::Raw, forced.clone(), 0));
+		assert_ok!(PoeModule::create_claim(Origin::signed(3), ClaimDigest::Raw, transferred.clone(), None, None));
+		assert_ok!(PoeModule::force_transfer_claim(Origin::root(), ClaimDigest::Raw, transferred.clone(), 2, 0));
+
+		System::set_block_number(4);
+		for claim in [&forced, &transferred] {
+			let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+			assert_ok!(PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), salt));
+			assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(2));
+			assert!(PoeModule::claim_committed_at(&bounded_claim).is_none());
+			System::assert_last_event(mock::Event::PoeModule(crate::Event::RevealSkipped(1, bounded_claim)));
+		}
+		// 只剩下later_salt的承诺押金
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		// 拥有者自己转移之后，更早的承诺者可以通过揭示取得
+		System::set_block_number(5);
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(2), ClaimDigest::Raw, forced.clone(), 3, None));
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(3), ClaimDigest::Raw, forced.clone()));
+		assert_ok!(PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, forced.clone(), later_salt));
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, forced).unwrap();
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+	})
+}
+
+// 超过揭示窗口的承诺在区块开始时被清理
+#[test]
+fn stale_commitments_are_garbage_collected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let salt = H256::repeat_byte(7);
+		let commitment = PoeModule::commitment_of(ClaimDigest::Raw, &claim, &salt);
+		assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+		assert_ok!(PoeModule::commit_claim(Origin::signed(2), H256::repeat_byte(1)));
+		assert_noop!(PoeModule::commit_claim(Origin::signed(3), H256::repeat_byte(2)), Error::<Test>::TooManyCommitments);
+
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert!(PoeModule::commitments(commitment).is_some());
+
+		System::set_block_number(12);
+		PoeModule::on_initialize(12);
+		assert_eq!(PoeModule::commitments(commitment), None);
+		assert!(!crate::CommitmentExpiries::<Test>::contains_key(12));
+		// 没有揭示的承诺押金被罚没
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 995);
+		System::assert_has_event(mock::Event::PoeModule(crate::Event::CommitmentExpired(1, commitment)));
+		assert_noop!(
+			PoeModule::reveal_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), salt),
+			Error::<Test>::CommitmentNotExist
+		);
+	})
+}
+
+// 只有ForceOrigin可以强制操作存证，事件中带有原因代码
+#[test]
+fn force_operations_require_force_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+
+		assert_noop!(
+			PoeModule::force_create_claim(Origin::signed(1), 1, ClaimDigest::Raw, claim.clone(), 7),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::force_create_claim(Origin::root(), 1, ClaimDigest::Raw, claim.clone(), 7));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimForceCreated(1, bounded_claim.clone(), 7)));
+
+		assert_noop!(
+			PoeModule::force_transfer_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), 2, 8),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PoeModule::force_revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 9, vec![]),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+// 强制转移不需要双方同意，押金随存证转到新拥有者
+#[test]
+fn force_transfer_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 3, None));
+
+		assert_noop!(
+			PoeModule::force_transfer_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 4, 8),
+			Error::<Test>::InsufficientDeposit
+		);
+		assert_ok!(PoeModule::force_transfer_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 2, 8));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 12);
+		// 之前的转移请求作废
+		assert_eq!(PoeModule::pending_transfers(&bounded_claim), None);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimForceTransferred(1, bounded_claim, 2, 8)));
+	})
+}
+
+// 强制撤销存证，押金退还给原拥有者
+#[test]
+fn force_revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 9, vec![]));
+		assert_eq!(PoeModule::proof_of(&bounded_claim), None);
+		assert_eq!(Balances::reserved_balance(1), 5);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimForceRevoked(1, bounded_claim, 9)));
+
+		assert_noop!(
+			PoeModule::force_revoke_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 9, vec![]),
+			Error::<Test>::ClaimNotExist
+		);
+	})
+}
+
+// 争议期间存证不能撤销和转移
+#[test]
+fn disputed_claim_is_locked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 3, None));
+
+		assert_noop!(
+			PoeModule::dispute_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::CannotDisputeOwnClaim
+		);
+		assert_noop!(
+			PoeModule::dispute_claim(Origin::signed(4), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::InsufficientDeposit
+		);
+		assert_ok!(PoeModule::dispute_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+		assert_eq!(Balances::reserved_balance(2), 100);
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimDisputed(2, 1, bounded_claim)));
+		assert_noop!(
+			PoeModule::dispute_claim(Origin::signed(3), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::ClaimDisputed
+		);
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]),
+			Error::<Test>::ClaimDisputed
+		);
+		assert_noop!(
+			PoeModule::propose_transfer(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 3, None),
+			Error::<Test>::ClaimDisputed
+		);
+		assert_noop!(
+			PoeModule::accept_transfer(Origin::signed(3), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::ClaimDisputed
+		);
+	})
+}
+
+// 驳回争议时罚没保证金，存证仍属于原拥有者
+#[test]
+fn rejected_dispute_slashes_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::dispute_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+
+		assert_noop!(
+			PoeModule::resolve_dispute(Origin::signed(1), ClaimDigest::Raw, claim.clone(), DisputeResolution::Rejected),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::resolve_dispute(Origin::root(), ClaimDigest::Raw, claim.clone(), DisputeResolution::Rejected));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 900);
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::DisputeResolved(2, bounded_claim, DisputeResolution::Rejected)));
+
+		assert_noop!(
+			PoeModule::resolve_dispute(Origin::root(), ClaimDigest::Raw, claim.clone(), DisputeResolution::Rejected),
+			Error::<Test>::NoDispute
+		);
+		// 争议结束后可以正常撤销
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+	})
+}
+
+// 支持争议时存证转给发起者，退还保证金
+#[test]
+fn upheld_dispute_transfers_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::dispute_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone()));
+
+		assert_ok!(PoeModule::resolve_dispute(Origin::root(), ClaimDigest::Raw, claim.clone(), DisputeResolution::Upheld));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		// 只保留存证押金
+		assert_eq!(Balances::reserved_balance(2), 12);
+		assert_eq!(PoeModule::disputes(&bounded_claim), None);
+	})
+}
+
+// 第三方为存证背书，不影响存证的所有权
+#[test]
+fn attest_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+
+		assert_noop!(
+			PoeModule::attest(Origin::signed(2), ClaimDigest::Raw, claim.clone(), b"reviewed".to_vec()),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_noop!(
+			PoeModule::attest(Origin::signed(2), ClaimDigest::Raw, claim.clone(), vec![0; 65]),
+			Error::<Test>::AttestationTooLong
+		);
+
+		assert_ok!(PoeModule::attest(Origin::signed(2), ClaimDigest::Raw, claim.clone(), b"reviewed".to_vec()));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimAttested(2, bounded_claim.clone())));
+		// 再次背书只修改背书内容
+		assert_ok!(PoeModule::attest(Origin::signed(2), ClaimDigest::Raw, claim.clone(), b"approved".to_vec()));
+		assert_eq!(PoeModule::attestation_count(&bounded_claim), 1);
+		assert_eq!(PoeModule::attestations(&bounded_claim, 2).unwrap().payload.into_inner(), b"approved".to_vec());
+
+		assert_ok!(PoeModule::attest(Origin::signed(3), ClaimDigest::Raw, claim.clone(), vec![]));
+		assert_noop!(
+			PoeModule::attest(Origin::signed(4), ClaimDigest::Raw, claim.clone(), vec![]),
+			Error::<Test>::TooManyAttestations
+		);
+
+		assert_ok!(PoeModule::withdraw_attestation(Origin::signed(3), ClaimDigest::Raw, claim.clone()));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::AttestationWithdrawn(3, bounded_claim.clone())));
+		assert_noop!(
+			PoeModule::withdraw_attestation(Origin::signed(3), ClaimDigest::Raw, claim.clone()),
+			Error::<Test>::AttestationNotExist
+		);
+		assert_ok!(PoeModule::attest(Origin::signed(4), ClaimDigest::Raw, claim.clone(), vec![]));
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(1));
+	})
+}
+
+// 撤销存证时清理所有背书
+#[test]
+fn revoke_claim_removes_attestations() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::attest(Origin::signed(2), ClaimDigest::Raw, claim.clone(), vec![1]));
+		assert_ok!(PoeModule::attest(Origin::signed(3), ClaimDigest::Raw, claim.clone(), vec![2]));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		assert_eq!(crate::Attestations::<Test>::iter_prefix(&bounded_claim).count(), 0);
+		assert_eq!(PoeModule::attestation_count(&bounded_claim), 0);
+
+		// 重新创建的存证没有之前的背书
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(PoeModule::attestations(&bounded_claim, 2), None);
+	})
+}
+
+// 撤销存证时留下墓碑，查询可以区分从未存在和已被撤销
+#[test]
+fn revoke_claim_leaves_tombstone() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_eq!(PoeModule::claim_status(ClaimDigest::Raw, claim.clone()), ClaimStatus::NeverExisted);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(PoeModule::claim_status(ClaimDigest::Raw, claim.clone()), ClaimStatus::Active(1, 1));
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 3, vec![0; 33]),
+			Error::<Test>::NoteTooLong
+		);
+		System::set_block_number(2);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 3, b"superseded".to_vec()));
+		let tombstone = PoeModule::revoked(&bounded_claim).unwrap();
+		assert_eq!(tombstone.revoker, Some(1));
+		assert_eq!(tombstone.revoked_at, 2);
+		assert_eq!(tombstone.reason, 3);
+		assert_eq!(tombstone.note.clone().into_inner(), b"superseded".to_vec());
+		assert_eq!(PoeModule::claim_status(ClaimDigest::Raw, claim.clone()), ClaimStatus::Revoked(tombstone));
+
+		// 默认允许重新创建，墓碑仍然保留
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_eq!(PoeModule::claim_status(ClaimDigest::Raw, claim.clone()), ClaimStatus::Active(2, 2));
+		assert!(PoeModule::revoked(&bounded_claim).is_some());
+
+		// 强制撤销的墓碑没有撤销账户
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), ClaimDigest::Raw, claim.clone(), 9, vec![]));
+		assert_eq!(
+			PoeModule::revoked(&bounded_claim),
+			Some(Tombstone { revoker: None, revoked_at: 2, reason: 9, note: Default::default() })
+		);
+	})
+}
+
+// 禁止重新创建时，已被撤销的存证不能再创建
+#[test]
+fn tombstoned_claim_cannot_be_reregistered() {
+	new_test_ext().execute_with(|| {
+		ForbidReregistration::set(true);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]));
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), None, None),
+			Error::<Test>::ClaimTombstoned
+		);
+		let nonce = mine(ClaimDigest::Raw, &claim);
+		assert_noop!(
+			PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, claim.clone(), nonce),
+			Error::<Test>::ClaimTombstoned
+		);
+
+		// 到期清理的存证不是撤销，可以重新创建
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![2, 3], Some(2), None));
+		PoeModule::on_initialize(2);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![2, 3], None, None));
+		ForbidReregistration::set(false);
+	})
+}
+
+// 测试注册命名空间和管理员
+#[test]
+fn namespace_admins_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::NamespaceCreated(1, 1)));
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert_ok!(PoeModule::create_namespace(Origin::signed(2)));
+		assert_eq!(PoeModule::namespaces(2).map(|ns| ns.owner), Some(2));
+
+		assert_noop!(PoeModule::add_namespace_admin(Origin::signed(2), 1, 3), Error::<Test>::NotNamespaceOwner);
+		assert_noop!(PoeModule::add_namespace_admin(Origin::signed(1), 3, 2), Error::<Test>::NamespaceNotExist);
+		assert_ok!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 2));
+		assert!(PoeModule::is_namespace_admin(1, &2));
+		assert_noop!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 2), Error::<Test>::AlreadyNamespaceAdmin);
+		assert_ok!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 3));
+		assert_noop!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 4), Error::<Test>::TooManyNamespaceAdmins);
+
+		assert_ok!(PoeModule::remove_namespace_admin(Origin::signed(1), 1, 2));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::NamespaceAdminRemoved(1, 2)));
+		assert!(!PoeModule::is_namespace_admin(1, &2));
+		assert_noop!(PoeModule::remove_namespace_admin(Origin::signed(1), 1, 2), Error::<Test>::NamespaceAdminNotExist);
+
+		// 账户4的余额不足以保留押金
+		assert_noop!(PoeModule::create_namespace(Origin::signed(4)), Error::<Test>::InsufficientDeposit);
+	})
+}
+
+// 注册的命名空间只有拥有者和管理员可以创建存证，不同命名空间中相同的存证互不冲突
+#[test]
+fn create_claim_in_namespace() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_noop!(
+			PoeModule::create_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, claim.clone(), None, None),
+			Error::<Test>::NamespaceNotExist
+		);
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		assert_ok!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 2));
+		assert_noop!(
+			PoeModule::create_claim_in(Origin::signed(3), 1, ClaimDigest::Raw, claim.clone(), None, None),
+			Error::<Test>::NoNamespacePermission
+		);
+
+		assert_ok!(PoeModule::create_claim_in(Origin::signed(2), 1, ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(3), ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::create_claim_in(
+			Origin::signed(3),
+			DEFAULT_NAMESPACE,
+			ClaimDigest::Raw,
+			vec![2, 3],
+			None,
+			None
+		));
+
+		let namespaced = PoeModule::bounded_claim_in(1, ClaimDigest::Raw, claim.clone()).unwrap();
+		let global = PoeModule::bounded_claim(ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_eq!(PoeModule::proof_of(&namespaced).map(|record| record.owner), Some(2));
+		assert_eq!(PoeModule::proof_of(&global).map(|record| record.owner), Some(3));
+		assert_noop!(
+			PoeModule::create_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, claim.clone(), None, None),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		// Runtime API只查询默认命名空间
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, claim.clone()).map(|(owner, _)| owner), Some(3));
+		assert!(PoeModule::owned_claims(2, None, 10).is_empty());
+		assert_eq!(PoeModule::claims_of(&2, None, 10), vec![namespaced]);
+	})
+}
+
+// 命名空间的拥有者和管理员可以撤销和转移其中的存证，其他命名空间的管理员不可以
+#[test]
+fn namespace_admin_can_revoke_and_transfer() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		assert_ok!(PoeModule::create_namespace(Origin::signed(3)));
+		assert_ok!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 2));
+		assert_ok!(PoeModule::create_claim_in(Origin::signed(2), 1, ClaimDigest::Raw, claim.clone(), None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, claim.clone(), None, None));
+
+		// 命名空间的权限不影响默认命名空间
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, claim.clone(), 0, vec![]),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::revoke_claim_in(Origin::signed(3), 1, ClaimDigest::Raw, claim.clone(), 0, vec![]),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::transfer_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, claim.clone(), 3, None));
+		assert_ok!(PoeModule::accept_transfer_in(Origin::signed(3), 1, ClaimDigest::Raw, claim.clone()));
+		let bounded_claim = PoeModule::bounded_claim_in(1, ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_eq!(PoeModule::proof_of(&bounded_claim).map(|record| record.owner), Some(3));
+
+		assert_ok!(PoeModule::revoke_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, claim.clone(), 0, vec![]));
+		System::assert_has_event(mock::Event::PoeModule(crate::Event::ClaimRevoked(3, bounded_claim.clone())));
+		assert_eq!(PoeModule::proof_of(&bounded_claim), None);
+		assert_eq!(Balances::reserved_balance(3), 55);
+		assert!(PoeModule::claim_exists(ClaimDigest::Raw, claim));
+	})
+}
+
+// 特权操作、争议、背书、元数据和版本都可以指定命名空间
+#[test]
+fn namespaced_claim_calls_work() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let namespaced = PoeModule::bounded_claim_in(1, ClaimDigest::Raw, claim.clone()).unwrap();
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		assert_ok!(PoeModule::add_namespace_admin(Origin::signed(1), 1, 2));
+
+		assert_noop!(
+			PoeModule::force_create_claim_in(Origin::root(), 3, 2, ClaimDigest::Raw, claim.clone(), 7),
+			Error::<Test>::NamespaceNotExist
+		);
+		assert_ok!(PoeModule::force_create_claim_in(Origin::root(), 3, 1, ClaimDigest::Raw, claim.clone(), 7));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimForceCreated(3, namespaced.clone(), 7)));
+		assert!(!PoeModule::claim_exists(ClaimDigest::Raw, claim.clone()));
+
+		// 命名空间的管理员可以修改其中存证的元数据
+		let metadata = ClaimMetadata { description: b"v1".to_vec(), ..Default::default() };
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(3), ClaimDigest::Raw, claim.clone(), metadata.clone()),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_ok!(PoeModule::set_claim_metadata_in(Origin::signed(2), 1, ClaimDigest::Raw, claim.clone(), metadata));
+		assert_eq!(PoeModule::claim_info(&namespaced).map(|info| info.description.into_inner()), Some(b"v1".to_vec()));
+
+		assert_ok!(PoeModule::attest_in(Origin::signed(4), 1, ClaimDigest::Raw, claim.clone(), vec![1]));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimAttested(4, namespaced.clone())));
+		assert_ok!(PoeModule::withdraw_attestation_in(Origin::signed(4), 1, ClaimDigest::Raw, claim.clone()));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::AttestationWithdrawn(4, namespaced.clone())));
+
+		// 争议期间命名空间的管理员也不能撤销，裁决支持后存证转给发起者
+		assert_ok!(PoeModule::dispute_claim_in(Origin::signed(2), 1, ClaimDigest::Raw, claim.clone()));
+		assert_noop!(
+			PoeModule::revoke_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, claim.clone(), 0, vec![]),
+			Error::<Test>::ClaimDisputed
+		);
+		assert_ok!(PoeModule::resolve_dispute_in(
+			Origin::root(),
+			1,
+			ClaimDigest::Raw,
+			claim.clone(),
+			DisputeResolution::Upheld
+		));
+		assert_eq!(PoeModule::proof_of(&namespaced).map(|record| record.owner), Some(2));
+
+		// 新版本创建在同一个命名空间中
+		let v2 = PoeModule::bounded_claim_in(1, ClaimDigest::Raw, vec![0, 2]).unwrap();
+		assert_ok!(PoeModule::supersede_claim_in(
+			Origin::signed(2),
+			1,
+			ClaimDigest::Raw,
+			claim.clone(),
+			ClaimDigest::Raw,
+			vec![0, 2]
+		));
+		assert_eq!(PoeModule::version_chain(&v2), vec![namespaced.clone(), v2.clone()]);
+		assert!(!PoeModule::claim_exists(ClaimDigest::Raw, vec![0, 2]));
+
+		// 代替拥有者创建时，拥有者需要有命名空间的权限
+		assert_ok!(PoeModule::approve_operator(Origin::signed(2), 4, None, None));
+		assert_ok!(PoeModule::approve_operator(Origin::signed(3), 4, None, None));
+		assert_noop!(
+			PoeModule::create_claim_on_behalf_in(Origin::signed(4), 3, 1, ClaimDigest::Raw, vec![0, 3], None, None),
+			Error::<Test>::NoNamespacePermission
+		);
+		assert_ok!(PoeModule::create_claim_on_behalf_in(Origin::signed(4), 2, 1, ClaimDigest::Raw, vec![0, 3], None, None));
+
+		// 不是命名空间管理员的拥有者不能在命名空间中创建新版本
+		let v3 = PoeModule::bounded_claim_in(1, ClaimDigest::Raw, vec![0, 3]).unwrap();
+		assert_ok!(PoeModule::force_transfer_claim_in(Origin::root(), 1, ClaimDigest::Raw, vec![0, 3], 3, 7));
+		assert_eq!(PoeModule::proof_of(&v3).map(|record| record.owner), Some(3));
+		assert_noop!(
+			PoeModule::supersede_claim_in(Origin::signed(3), 1, ClaimDigest::Raw, vec![0, 3], ClaimDigest::Raw, vec![0, 4]),
+			Error::<Test>::NoNamespacePermission
+		);
+
+		assert_ok!(PoeModule::force_revoke_claim_in(Origin::root(), 1, ClaimDigest::Raw, vec![0, 3], 7, vec![]));
+		assert_eq!(PoeModule::proof_of(&v3), None);
+	})
+}
+
+// 测试用新版本取代存证，旧版本仍然可以证明
+#[test]
+fn supersede_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let v1 = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		let v2 = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap();
+		let v3 = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 3]).unwrap();
+		assert_noop!(
+			PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 2]),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_noop!(
+			PoeModule::supersede_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 2]),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 2]));
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimSuperseded(1, v1.clone(), v2.clone())));
+		assert_eq!(PoeModule::proof_of(&v1), Some(ClaimRecord { owner: 1, block_number: 1, moment: 0 }));
+		assert_eq!(PoeModule::proof_of(&v2), Some(ClaimRecord { owner: 1, block_number: 1, moment: 0 }));
+		assert_eq!(Balances::reserved_balance(1), 24);
+		assert_noop!(
+			PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 3]),
+			Error::<Test>::ClaimAlreadySuperseded
+		);
+		assert_ok!(PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], ClaimDigest::Raw, vec![0, 3]));
+
+		// 从版本链的任意位置都能查到完整的版本链
+		let chain = vec![v1.clone(), v2.clone(), v3.clone()];
+		assert_eq!(PoeModule::version_chain(&v1), chain);
+		assert_eq!(PoeModule::version_chain(&v2), chain);
+		assert_eq!(PoeModule::version_chain(&v3), chain);
+		assert_eq!(PoeModule::latest_version(&v1), v3.clone());
+		assert_eq!(PoeModule::latest_version(&v3), v3.clone());
+	})
+}
+
+// 撤销版本链中间的存证时，前后两个版本直接相连
+#[test]
+fn revoke_claim_relinks_versions() {
+	new_test_ext().execute_with(|| {
+		let v1 = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		let v2 = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap();
+		let v3 = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 3]).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_ok!(PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 2]));
+		assert_ok!(PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], ClaimDigest::Raw, vec![0, 3]));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], 0, vec![]));
+		assert_eq!(PoeModule::versions(&v2), None);
+		assert_eq!(PoeModule::version_chain(&v1), vec![v1.clone(), v3.clone()]);
+
+		// 撤销最新版本后，旧版本可以再次被取代
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 3], 0, vec![]));
+		assert_eq!(PoeModule::versions(&v1), None);
+		assert_eq!(PoeModule::version_chain(&v1), vec![v1.clone()]);
+		assert_ok!(PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 3]));
+		assert_eq!(PoeModule::latest_version(&v1), v3);
+	})
+}
+
+// 测试把v0版本的(拥有者, 区块)元组依次迁移到最新版本的存证记录
+#[test]
+fn migrate_from_v0_works() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		let first = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		let second = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap();
+		migrations::v0::Proofs::<Test>::insert((first.1).1.clone(), (1, 5));
+		migrations::v0::Proofs::<Test>::insert((second.1).1.clone(), (2, 7));
+
+		PoeModule::on_runtime_upgrade();
+
+		// v0的存证移到默认命名空间，摘要算法为Raw，旧的Key被删除
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert!(!migrations::v0::Proofs::<Test>::contains_key((first.1).1.clone()));
+		assert_eq!(PoeModule::proof_of(&first), Some(ClaimRecord { owner: 1, block_number: 5, moment: 0 }));
+		assert_eq!(PoeModule::proof_of(&second), Some(ClaimRecord { owner: 2, block_number: 7, moment: 0 }));
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, vec![0, 1]), Some((1, 5)));
+		assert_eq!(PoeModule::claims_of(&2, None, 10), vec![second.clone()]);
+		assert_eq!(PoeModule::claim_count(2), 1);
+
+		// 补上没有元数据、创建时间为0的存证信息和在原区块创建的所有权记录
+		assert_eq!(PoeModule::claim_info(&first).map(|info| info.created_at), Some(0));
+		assert_eq!(
+			PoeModule::provenance_of(&first),
+			vec![ProvenanceRecord { owner: 1, acquired_at: 5, kind: TransferKind::Created }]
+		);
+		let mut created = PoeModule::created_claims(0, 1, None, 10);
+		created.sort_by_key(|(_, _, claim)| claim.clone());
+		assert_eq!(created, vec![(0, ClaimDigest::Raw, vec![0, 1]), (0, ClaimDigest::Raw, vec![0, 2])]);
+
+		// 迁移过来的存证没有押金，撤销时不退还
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 2], 0, vec![]));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(PoeModule::claim_count(2), 0);
+	})
+}
+
+// 账户的v0存证超过MaxClaimsPerAccount时不做任何修改，存储版本保持为0
+#[test]
+fn migrate_from_v0_refuses_unindexable_claims() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		let v0_key = |i: u8| {
+			let (_, (_, claim)) = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, i]).unwrap();
+			claim
+		};
+		let max = <Test as Config>::MaxClaimsPerAccount::get();
+		for i in 0 ..= max as u8 {
+			migrations::v0::Proofs::<Test>::insert(v0_key(i), (1, 5));
+		}
+		assert!(migrations::v1::check::<Test>().is_err());
+
+		PoeModule::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 0);
+		assert!(migrations::v0::Proofs::<Test>::contains_key(v0_key(0)));
+		assert_eq!(PoeModule::claim_count(1), 0);
+	})
+}
+
+// 测试为v1版本的存证记录增加时间
+#[test]
+fn migrate_from_v1_works() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<PoeModule>();
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		migrations::v1::Proofs::<Test>::insert(
+			bounded_claim.0,
+			&bounded_claim.1,
+			migrations::v1::ClaimRecord { owner: 1, block_number: 5 },
+		);
+
+		PoeModule::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert_eq!(PoeModule::proof_of(&bounded_claim), Some(ClaimRecord { owner: 1, block_number: 5, moment: 0 }));
+	})
+}
+
+// 已经是最新版本时迁移什么都不做
+#[test]
+fn migrate_skips_current_version() {
+	new_test_ext().execute_with(|| {
+		crate::STORAGE_VERSION.put::<PoeModule>();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		let record = PoeModule::proof_of(&bounded_claim);
+
+		PoeModule::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), crate::STORAGE_VERSION);
+		assert_eq!(PoeModule::proof_of(&bounded_claim), record);
+	})
+}
+
+// 测试从创世存证开始
+#[test]
+fn genesis_claims_work() {
+	new_test_ext_with_claims(vec![(1, vec![0, 1]), (2, vec![0, 2]), (1, vec![0, 3])]).execute_with(|| {
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, vec![0, 1]), Some((1, 0)));
+		assert_eq!(PoeModule::get_claim(ClaimDigest::Raw, vec![0, 2]), Some((2, 0)));
+		assert_eq!(PoeModule::claim_count(1), 2);
+		// 创世存证不保留押金，拥有者可以正常撤销
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], 0, vec![]));
+		assert!(!PoeModule::claim_exists(ClaimDigest::Raw, vec![0, 1]));
+		assert_eq!(PoeModule::claim_count(1), 1);
+	})
+}
+
+#[test]
+#[should_panic(expected = "duplicate claim in genesis config")]
+fn genesis_rejects_duplicate_claims() {
+	new_test_ext_with_claims(vec![(1, vec![0, 1]), (2, vec![0, 1])]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim exceeds MaxClaimLength")]
+fn genesis_rejects_too_long_claims() {
+	new_test_ext_with_claims(vec![(1, vec![0; 513])]);
+}
+
+// 创建和转移存证时记录Unix时间，并可以按创建时间查询存证
+#[test]
+fn claim_moments_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(12_000);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		let first = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		System::assert_last_event(mock::Event::PoeModule(crate::Event::ClaimCreated(1, first.clone(), 12)));
+		assert_eq!(PoeModule::proof_of(&first).map(|record| record.moment), Some(12));
+
+		Timestamp::set_timestamp(30_000);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], None, None));
+		let second = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap();
+
+		// 转移时更新取得存证的时间，创建时间不变
+		Timestamp::set_timestamp(45_000);
+		transfer(1, 2, &[0, 1]);
+		assert_eq!(PoeModule::proof_of(&first).map(|record| record.moment), Some(45));
+
+		let between =
+			|from, to, cursor, limit| PoeModule::claims_created_between(DEFAULT_NAMESPACE, from, to, cursor, limit);
+		assert_eq!(between(12, 30, None, 10), vec![(12, first.1.clone())]);
+		assert_eq!(between(30, 31, None, 10), vec![(30, second.1.clone())]);
+		assert!(between(13, 30, None, 10).is_empty());
+		// 按创建时间排列
+		assert_eq!(between(0, 100, None, 10), vec![(12, first.1.clone()), (30, second.1.clone())]);
+
+		// 按页查询，用上一页的最后一个结果继续
+		let page = between(0, 100, None, 1);
+		assert_eq!(page, vec![(12, first.1.clone())]);
+		let next = between(0, 100, page.last().cloned(), 1);
+		assert_eq!(next, vec![(30, second.1.clone())]);
+		assert!(between(0, 100, next.last().cloned(), 1).is_empty());
+		// cursor指向的存证被撤销后仍然可以继续翻页
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 1], 0, vec![]));
+		assert_eq!(between(0, 100, page.last().cloned(), 1), vec![(30, second.1.clone())]);
+		assert_eq!(between(0, 100, None, 10), vec![(30, second.1.clone())]);
+
+		// 每个命名空间分别索引，Runtime API只查询默认命名空间
+		Timestamp::set_timestamp(30_000);
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		assert_ok!(PoeModule::create_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, vec![0, 3], None, None));
+		let third = PoeModule::bounded_claim_in(1, ClaimDigest::Raw, vec![0, 3]).unwrap();
+		assert_eq!(PoeModule::claims_created_between(1, 30, 31, None, 10), vec![(30, third.1)]);
+		assert_eq!(between(30, 31, None, 10), vec![(30, second.1.clone())]);
+		assert_eq!(PoeModule::created_claims(30, 31, None, 10), vec![(30, ClaimDigest::Raw, vec![0, 2])]);
+		assert!(PoeModule::created_claims(12, 30, None, 10).is_empty());
+	})
+}
+
+// 账户在任意连续QuotaWindow个区块内创建的存证数量有上限，最早的存证滑出窗口后才能继续创建
+#[test]
+fn account_quota_slides_with_window() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerWindow::set(2);
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim_in(
+			Origin::signed(1),
+			DEFAULT_NAMESPACE,
+			ClaimDigest::Raw,
+			vec![0, 2],
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 3], None, None),
+			Error::<Test>::QuotaExceeded
+		);
+		// 其他账户不受影响
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 3], None, None));
+
+		// 区块1的存证在区块11滑出窗口
+		System::set_block_number(10);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 4], None, None),
+			Error::<Test>::QuotaExceeded
+		);
+		System::set_block_number(11);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 4], None, None));
+		assert_eq!(PoeModule::quota_usage(1).into_inner(), vec![5, 11]);
+
+		// 区块5的存证仍然在窗口中，不会因为新的窗口开始而重置
+		System::set_block_number(12);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 5], None, None),
+			Error::<Test>::QuotaExceeded
+		);
+		System::set_block_number(15);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 5], None, None));
+		assert_eq!(PoeModule::quota_usage(1).into_inner(), vec![11, 15]);
+		MaxClaimsPerWindow::set(100);
+	})
+}
+
+// 所有创建存证的调用都占用新存证拥有者的配额
+#[test]
+fn quota_applies_to_every_creation_path() {
+	use frame_support::traits::tokens::nonfungibles::Mutate;
+
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerWindow::set(1);
+		System::set_block_number(1);
+
+		// 操作员代替拥有者创建，占用拥有者的配额
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 2, None, None));
+		assert_ok!(PoeModule::create_claim_on_behalf(Origin::signed(2), 1, ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 2], None, None),
+			Error::<Test>::QuotaExceeded
+		);
+		assert_noop!(
+			PoeModule::supersede_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], ClaimDigest::Raw, vec![0, 2]),
+			Error::<Test>::QuotaExceeded
+		);
+		let item = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap().1;
+		assert_noop!(PoeModule::mint_into(&(), &item, &1), Error::<Test>::QuotaExceeded);
+
+		// relayer代替受益人创建，占用受益人的配额
+		let payload = PoeModule::create_claim_payload(&4, ClaimDigest::Raw, &[0, 3], 0);
+		assert_ok!(PoeModule::create_claim_for(Origin::signed(2), 4, ClaimDigest::Raw, vec![0, 3], 0, TestSignature(4, payload)));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 4], None, None));
+
+		// 特权调用同样受配额限制
+		assert_ok!(PoeModule::force_create_claim(Origin::root(), 3, ClaimDigest::Raw, vec![0, 5], 0));
+		assert_noop!(
+			PoeModule::force_create_claim(Origin::root(), 3, ClaimDigest::Raw, vec![0, 6], 0),
+			Error::<Test>::QuotaExceeded
+		);
+
+		// 无签名提交的存证共用模块账户的配额
+		let nonce = mine(ClaimDigest::Raw, &[0, 7]);
+		assert_ok!(PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, vec![0, 7], nonce));
+		let nonce = mine(ClaimDigest::Raw, &[0, 8]);
+		let call = crate::Call::<Test>::create_claim_unsigned { digest: ClaimDigest::Raw, claim: vec![0, 8], nonce };
+		assert_eq!(
+			PoeModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Custom(crate::extensions::QUOTA_EXCEEDED).into()
+		);
+		assert_noop!(
+			PoeModule::create_claim_unsigned(Origin::none(), ClaimDigest::Raw, vec![0, 8], nonce),
+			Error::<Test>::QuotaExceeded
+		);
+		MaxClaimsPerWindow::set(100);
+	})
+}
+
+// 每个区块创建的存证数量有上限，进入新的区块后重置
+#[test]
+fn block_quota_resets_in_next_block() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerBlock::set(2);
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 2], None, None));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(3), ClaimDigest::Raw, vec![0, 3], None, None),
+			Error::<Test>::BlockQuotaExceeded
+		);
+		assert_eq!(PoeModule::block_claims(), (1, 2));
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::create_claim(Origin::signed(3), ClaimDigest::Raw, vec![0, 3], None, None));
+		assert_eq!(PoeModule::block_claims(), (2, 1));
+		MaxClaimsPerBlock::set(100);
+	})
+}
+
+// 超过配额的交易在进入交易池之前就被过滤
+#[test]
+fn check_claim_quota_filters_transactions() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerWindow::set(1);
+		MaxClaimsPerBlock::set(2);
+		System::set_block_number(1);
+		let create = |claim: Vec<u8>| -> Call {
+			Call::PoeModule(crate::Call::create_claim { digest: ClaimDigest::Raw, claim, expires_at: None, metadata: None })
+		};
+		let info = Default::default();
+
+		assert_ok!(CheckClaimQuota::<Test>::new().validate(&1, &create(vec![0, 1]), &info, 0));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_eq!(
+			CheckClaimQuota::<Test>::new().validate(&1, &create(vec![0, 2]), &info, 0),
+			Err(InvalidTransaction::Custom(crate::extensions::QUOTA_EXCEEDED).into())
+		);
+		// 其他调用不受配额限制
+		let attest =
+			Call::PoeModule(crate::Call::attest { digest: ClaimDigest::Raw, claim: vec![0, 1], payload: vec![] });
+		assert_ok!(CheckClaimQuota::<Test>::new().pre_dispatch(&1, &attest, &info, 0));
+
+		// 代替别人创建和取代存证时，检查的是新存证拥有者的配额
+		let on_behalf = |owner: u64| -> Call {
+			Call::PoeModule(crate::Call::create_claim_on_behalf {
+				owner,
+				digest: ClaimDigest::Raw,
+				claim: vec![0, 4],
+				expires_at: None,
+				metadata: None,
+			})
+		};
+		assert_ok!(CheckClaimQuota::<Test>::new().validate(&1, &on_behalf(2), &info, 0));
+		assert_eq!(
+			CheckClaimQuota::<Test>::new().validate(&2, &on_behalf(1), &info, 0),
+			Err(InvalidTransaction::Custom(crate::extensions::QUOTA_EXCEEDED).into())
+		);
+		let supersede = Call::PoeModule(crate::Call::supersede_claim {
+			digest: ClaimDigest::Raw,
+			claim: vec![0, 1],
+			new_digest: ClaimDigest::Raw,
+			new_claim: vec![0, 4],
+		});
+		assert_eq!(
+			CheckClaimQuota::<Test>::new().validate(&2, &supersede, &info, 0),
+			Err(InvalidTransaction::Custom(crate::extensions::QUOTA_EXCEEDED).into())
+		);
+
+		// 揭示已经存在的存证只会转移所有权，不占用配额；揭示新的存证则要检查配额
+		let reveal = |claim: Vec<u8>| -> Call {
+			Call::PoeModule(crate::Call::reveal_claim { digest: ClaimDigest::Raw, claim, salt: H256::repeat_byte(1) })
+		};
+		assert_ok!(CheckClaimQuota::<Test>::new().validate(&1, &reveal(vec![0, 1]), &info, 0));
+		assert_eq!(
+			CheckClaimQuota::<Test>::new().validate(&1, &reveal(vec![0, 5]), &info, 0),
+			Err(InvalidTransaction::Custom(crate::extensions::QUOTA_EXCEEDED).into())
+		);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 2], None, None));
+		assert_eq!(
+			CheckClaimQuota::<Test>::new().pre_dispatch(&3, &create(vec![0, 3]), &info, 0),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+		MaxClaimsPerWindow::set(100);
+		MaxClaimsPerBlock::set(100);
+	})
+}
+
+// 通过nonfungibles接口查询、创建、转移和销毁存证
+#[test]
+fn nonfungibles_interface_works() {
+	use codec::Encode;
+	use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(12_000);
+		let bounded_claim = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		let item = bounded_claim.1.clone();
+		assert_ok!(PoeModule::create_collection(&(), &1, &1));
+		assert_eq!(PoeModule::owner(&(), &item), None);
+
+		assert_ok!(PoeModule::mint_into(&(), &item, &1));
+		assert_eq!(PoeModule::owner(&(), &item), Some(1));
+		assert_eq!(Balances::reserved_balance(1), 12);
+		assert_eq!(PoeModule::attribute(&(), &item, b"created_at"), Some(12u64.encode()));
+		assert_eq!(PoeModule::attribute(&(), &item, b"block_number"), Some(1u64.encode()));
+		assert_eq!(PoeModule::attribute(&(), &item, b"unknown"), None);
+
+		assert_ok!(PoeModule::set_attribute(&(), &item, b"description", b"contract"));
+		assert_eq!(PoeModule::attribute(&(), &item, b"description"), Some(b"contract".to_vec()));
+		assert_noop!(PoeModule::set_attribute(&(), &item, b"created_at", b"0"), sp_runtime::TokenError::Unsupported);
+
+		assert!(PoeModule::can_transfer(&(), &item));
+		assert_ok!(PoeModule::transfer(&(), &item, &2));
+		assert_eq!(PoeModule::owner(&(), &item), Some(2));
+		assert_eq!(Balances::reserved_balance(2), 12);
+
+		assert_noop!(PoeModule::burn(&(), &item, Some(&1)), Error::<Test>::NotClaimOwner);
+		assert_ok!(PoeModule::burn(&(), &item, Some(&2)));
+		assert_eq!(PoeModule::owner(&(), &item), None);
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_eq!(PoeModule::revoked(&bounded_claim).and_then(|tombstone| tombstone.revoker), Some(2));
+		assert_noop!(PoeModule::transfer(&(), &item, &3), sp_runtime::TokenError::UnknownAsset);
+
+		// 不检查拥有者的销毁记录为拥有者撤销，不会被当成ForceOrigin强制撤销
+		let other = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap();
+		assert_ok!(PoeModule::mint_into(&(), &other.1, &3));
+		assert_ok!(PoeModule::burn(&(), &other.1, None));
+		assert_eq!(PoeModule::revoked(&other).and_then(|tombstone| tombstone.revoker), Some(3));
+	})
+}
+
+// 按集合和拥有者枚举存证，其他命名空间中的存证不在集合中
+#[test]
+fn nonfungibles_enumeration_works() {
+	use frame_support::traits::tokens::nonfungibles::InspectEnumerable;
+
+	new_test_ext().execute_with(|| {
+		let first = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 1]).unwrap();
+		let second = PoeModule::bounded_claim(ClaimDigest::Raw, vec![0, 2]).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), ClaimDigest::Raw, vec![0, 1], None, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), ClaimDigest::Raw, vec![0, 2], None, None));
+		assert_ok!(PoeModule::create_namespace(Origin::signed(1)));
+		assert_ok!(PoeModule::create_claim_in(Origin::signed(1), 1, ClaimDigest::Raw, vec![0, 3], None, None));
+
+		assert_eq!(PoeModule::collections().collect::<Vec<_>>(), vec![()]);
+		let mut items = PoeModule::items(&()).collect::<Vec<_>>();
+		items.sort_by_key(|(_, claim)| claim.clone());
+		assert_eq!(items, vec![first.1.clone(), second.1.clone()]);
+
+		assert_eq!(PoeModule::owned(&1).collect::<Vec<_>>(), vec![((), first.1.clone())]);
+		assert_eq!(PoeModule::owned_in_collection(&(), &2).collect::<Vec<_>>(), vec![second.1]);
+		assert_eq!(PoeModule::owned(&3).count(), 0);
+	})
+}